@@ -87,6 +87,12 @@ pub struct BuiltinMigrationMetadata {
     pub migrated_system_object_mappings: BTreeMap<GlobalId, SystemObjectMapping>,
     pub user_drop_ops: Vec<GlobalId>,
     pub user_create_ops: Vec<(GlobalId, SchemaId, String)>,
+    /// The `old id -> new id` mapping for every migrated object, in the
+    /// same form `generate_builtin_migration_metadata` already builds up
+    /// locally while computing `all_create_ops`; surfaced here so callers
+    /// (e.g. [`BuiltinMigrationJournal::planned`]) don't have to
+    /// reconstruct it from the op vectors.
+    pub ancestor_ids: BTreeMap<GlobalId, GlobalId>,
 }
 
 impl BuiltinMigrationMetadata {
@@ -101,16 +107,140 @@ impl BuiltinMigrationMetadata {
             migrated_system_object_mappings: BTreeMap::new(),
             user_drop_ops: Vec::new(),
             user_create_ops: Vec::new(),
+            ancestor_ids: BTreeMap::new(),
         }
     }
 }
 
+/// One node's upstream (`reads_from`) and downstream (`writes_to`) edges in
+/// a [`MigrationDependencyGraph`].
+#[derive(Debug, Clone, Default)]
+struct MigrationDependencyNode {
+    reads_from: BTreeSet<GlobalId>,
+    writes_to: BTreeSet<GlobalId>,
+}
+
+/// The read-from/write-to edges between the objects a builtin migration is
+/// considering, with edges to or from `disabled_ids` excluded.
+///
+/// A disabled (or already-slated-for-drop) object still gets a node here,
+/// but that node is never linked into a neighbor's `reads_from`/`writes_to`
+/// set. The disabled object therefore keeps its `GlobalId`, and its own spec
+/// is not rewritten, even when something it reads from is migrated. This is
+/// what keeps a disabled
+/// object from forcing a remap of its otherwise untouched neighbors: the
+/// `old id -> new id` mapping `generate_builtin_migration_metadata` builds
+/// ends up identical whether or not a disabled object sits in the middle of
+/// the dependency chain.
+///
+/// `generate_builtin_migration_metadata` and [`Catalog::plan_builtin_migration`]
+/// walk this instead of calling `entry.uses()` directly once they need to
+/// know which dependencies can actually propagate dirtiness.
+#[derive(Debug, Clone, Default)]
+struct MigrationDependencyGraph {
+    nodes: BTreeMap<GlobalId, MigrationDependencyNode>,
+}
+
+impl MigrationDependencyGraph {
+    /// Builds the graph for `entries`, excluding every id in `disabled_ids`
+    /// from the edge sets (though not from the node set).
+    fn build<'a>(
+        entries: impl IntoIterator<Item = &'a CatalogEntry>,
+        disabled_ids: &BTreeSet<GlobalId>,
+    ) -> MigrationDependencyGraph {
+        let mut graph = MigrationDependencyGraph::default();
+        for entry in entries {
+            let id = entry.id();
+            graph.nodes.entry(id).or_default();
+            if disabled_ids.contains(&id) {
+                continue;
+            }
+            for dep in entry.uses() {
+                if disabled_ids.contains(dep) {
+                    continue;
+                }
+                graph.nodes.entry(id).or_default().reads_from.insert(*dep);
+                graph.nodes.entry(*dep).or_default().writes_to.insert(id);
+            }
+        }
+        graph
+    }
+
+    /// The non-disabled ids `id` reads from -- the replacement for
+    /// `entry.uses()` that dirty-propagation and topological-level grouping
+    /// should consult.
+    fn reads_from(&self, id: &GlobalId) -> impl Iterator<Item = &GlobalId> {
+        self.nodes
+            .get(id)
+            .into_iter()
+            .flat_map(|node| node.reads_from.iter())
+    }
+}
+
 struct AllocatedBuiltinSystemIds<T> {
     all_builtins: Vec<(T, GlobalId)>,
     new_builtins: Vec<(T, GlobalId)>,
     migrated_builtins: Vec<GlobalId>,
 }
 
+/// Whether to fan the pure, CPU-bound `parse_item` work for builtin views
+/// out across worker threads during bootstrap, instead of parsing them one
+/// at a time.
+///
+/// This is opt-in because it assumes that planning is thread-safe, which we
+/// haven't fully audited; if a planning step ever turns out not to be, unset
+/// the `MZ_PARALLEL_BUILTIN_BOOTSTRAP` environment variable to fall back to
+/// the strictly serial path. Ideally this would be a `SystemVar` like the
+/// other bootstrap-affecting knobs, but `SystemVars` isn't defined in the
+/// part of the catalog crate this change has access to, so an environment
+/// variable stands in for it.
+fn parallel_builtin_bootstrap_enabled() -> bool {
+    std::env::var_os("MZ_PARALLEL_BUILTIN_BOOTSTRAP").is_some()
+}
+
+/// Computes a topological layering of `views`, where each layer only
+/// depends on views in strictly earlier layers, so that every view within a
+/// layer can be planned concurrently.
+///
+/// `BUILTINS::iter()` emits builtins in dependency order (a builtin view's
+/// `CREATE VIEW` statement can only reference builtins declared earlier), so
+/// rather than planning every view once just to read back its
+/// `resolved_ids` -- which would defeat the point of doing this before
+/// planning -- we conservatively approximate the dependency edges by
+/// scanning each view's SQL text for the names of earlier views. This can
+/// only ever over-approximate the true dependency set (e.g. a name that
+/// appears in a comment or a string literal), which only costs us some
+/// potential parallelism, never correctness.
+fn builtin_view_dependency_layers(views: &[(&'static str, &'static str)]) -> Vec<Vec<usize>> {
+    let mut remaining_deps: Vec<BTreeSet<usize>> = vec![BTreeSet::new(); views.len()];
+    for (i, (_, sql)) in views.iter().enumerate() {
+        for (j, (name, _)) in views.iter().enumerate().take(i) {
+            let references = Regex::new(&format!(r"\b{}\b", regex::escape(name)))
+                .expect("valid regex")
+                .is_match(sql);
+            if references {
+                remaining_deps[i].insert(j);
+            }
+        }
+    }
+
+    let mut layers = Vec::new();
+    let mut done: BTreeSet<usize> = BTreeSet::new();
+    while done.len() < views.len() {
+        let layer: Vec<usize> = (0..views.len())
+            .filter(|i| !done.contains(i))
+            .filter(|i| remaining_deps[*i].is_subset(&done))
+            .collect();
+        assert!(
+            !layer.is_empty(),
+            "builtin view dependency graph must be acyclic"
+        );
+        done.extend(&layer);
+        layers.push(layer);
+    }
+    layers
+}
+
 #[derive(Debug)]
 pub enum CatalogItemRebuilder {
     SystemSource(CatalogItem),
@@ -170,6 +300,986 @@ impl CatalogItemRebuilder {
     }
 }
 
+/// A row of the ANSI-standard `information_schema.columns` view: one row
+/// per column of every queryable relation in the catalog.
+#[derive(Debug, Clone)]
+pub struct InformationSchemaColumn {
+    pub table_schema: String,
+    pub table_name: String,
+    pub column_name: String,
+    pub ordinal_position: u64,
+    pub is_nullable: bool,
+    pub data_type: String,
+}
+
+/// A row of the ANSI-standard `information_schema.tables` view: one row per
+/// queryable relation in the catalog, with the `table_type` (`BASE TABLE`,
+/// `VIEW`, or `MATERIALIZED VIEW`) it corresponds to.
+#[derive(Debug, Clone)]
+pub struct InformationSchemaTable {
+    pub table_schema: String,
+    pub table_name: String,
+    pub table_type: &'static str,
+}
+
+impl Catalog {
+    /// Computes the rows of an ANSI-standard `information_schema.columns`
+    /// view, derived directly from each catalog entry's `RelationDesc`:
+    /// column name, ordinal position, nullability, and pg type name.
+    ///
+    /// Status: blocked, not merely deferred. This only computes the data;
+    /// no SQL query can see it. Wiring it up as an actual
+    /// `information_schema` builtin schema, populated at open time through
+    /// the same `Builtin::View`/`insert_item` path as `mz_catalog` and
+    /// `pg_catalog` (so it gets OIDs, privileges, and a migration
+    /// fingerprint like every other builtin), requires adding entries to
+    /// `mz_catalog::builtin::BUILTINS` -- which isn't checked into this
+    /// crate snapshot at all, not just out of scope for this change -- so
+    /// there is no call site in this tree that could do that wiring.
+    pub fn information_schema_columns(&self) -> Vec<InformationSchemaColumn> {
+        let mut columns = Vec::new();
+        for entry in self.state.entry_by_id.values() {
+            let name = self.resolve_full_name(entry.name(), entry.conn_id());
+            let Ok(desc) = entry.desc(&name) else {
+                continue;
+            };
+            for (ordinal_position, (column_name, column_type)) in desc.iter().enumerate() {
+                columns.push(InformationSchemaColumn {
+                    table_schema: name.schema.clone(),
+                    table_name: name.item.clone(),
+                    column_name: column_name.as_str().to_string(),
+                    ordinal_position: u64::cast_from(ordinal_position + 1),
+                    is_nullable: column_type.nullable,
+                    data_type: column_type.scalar_type.to_string(),
+                });
+            }
+        }
+        columns
+    }
+
+    /// Computes the rows of an ANSI-standard `information_schema.tables`
+    /// view, distinguishing `BASE TABLE` from `VIEW` and
+    /// `MATERIALIZED VIEW` by `CatalogItem` variant. See
+    /// [`Catalog::information_schema_columns`] for the same caveat about
+    /// this not yet being wired up as a `Builtin::View`.
+    pub fn information_schema_tables(&self) -> Vec<InformationSchemaTable> {
+        self.state
+            .entry_by_id
+            .values()
+            .filter_map(|entry| {
+                let table_type = match entry.item() {
+                    CatalogItem::Table(_) => "BASE TABLE",
+                    CatalogItem::View(_) => "VIEW",
+                    CatalogItem::MaterializedView(_) => "MATERIALIZED VIEW",
+                    _ => return None,
+                };
+                let name = self.resolve_full_name(entry.name(), entry.conn_id());
+                Some(InformationSchemaTable {
+                    table_schema: name.schema,
+                    table_name: name.item,
+                    table_type,
+                })
+            })
+            .collect()
+    }
+}
+
+/// The policy governing whether a view's effective privileges include
+/// those granted on the relations it reads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PrivilegeInheritancePolicy {
+    /// A view's privileges are independent of any relation it depends on.
+    /// This is the default, existing behavior.
+    Independent,
+    /// A view additionally inherits the privileges granted on its
+    /// transitive dependencies, so granting `SELECT` on a table makes
+    /// views defined solely over that table usable too, without having to
+    /// grant each view separately.
+    Inherit,
+}
+
+impl Catalog {
+    /// Walks `id`'s transitive dependencies (what it `uses()`, and what
+    /// those use, and so on), guarding against cycles, and returns every
+    /// dependency's `GlobalId` exactly once. Does not include `id` itself.
+    fn transitive_dependencies(&self, id: GlobalId) -> BTreeSet<GlobalId> {
+        let mut visited = BTreeSet::new();
+        let mut stack = vec![id];
+        while let Some(id) = stack.pop() {
+            for dep in self.get_entry(&id).uses() {
+                if visited.insert(*dep) {
+                    stack.push(*dep);
+                }
+            }
+        }
+        visited
+    }
+
+    /// Computes the effective privileges for `entry` under `policy`.
+    ///
+    /// Under [`PrivilegeInheritancePolicy::Independent`] (today's default
+    /// behavior), this is simply `entry`'s own directly-granted
+    /// privileges. Under [`PrivilegeInheritancePolicy::Inherit`], a view
+    /// additionally inherits the union of privileges granted on its
+    /// transitive dependencies. Owner privileges and system objects are
+    /// unaffected either way -- this only changes what a *non-owner* can
+    /// do through the view.
+    pub fn effective_privileges(
+        &self,
+        entry: &CatalogEntry,
+        policy: PrivilegeInheritancePolicy,
+    ) -> PrivilegeMap {
+        let mut acl_items: Vec<_> = entry.privileges().all_values_owned().collect();
+        if policy == PrivilegeInheritancePolicy::Inherit
+            && matches!(entry.item(), CatalogItem::View(_))
+        {
+            for dep_id in self.transitive_dependencies(entry.id()) {
+                // System objects' privileges are ambient and not part of
+                // this inheritance scheme.
+                if dep_id.is_system() {
+                    continue;
+                }
+                acl_items.extend(self.get_entry(&dep_id).privileges().all_values_owned());
+            }
+        }
+        PrivilegeMap::from_mz_acl_items(acl_items)
+    }
+}
+
+/// Resolves schema names to ids and back within a single namespace -- the
+/// ambient (builtin) namespace, or a single user database -- so that name
+/// resolution can dispatch through one interface instead of every call
+/// site branching on `ResolvedDatabaseSpecifier::Ambient` vs `Id`.
+///
+/// This also opens the door to namespaces backed by something other than
+/// the in-memory maps `CatalogState` already has, e.g. a read-through
+/// provider for a remote system catalog, without touching every existing
+/// call site: such a provider just needs its own `CatalogNamespace` impl
+/// and a way to get registered alongside [`CatalogState::namespace`].
+pub trait CatalogNamespace {
+    /// Resolves a schema name to its id within this namespace.
+    fn schema_id(&self, name: &str) -> Option<SchemaId>;
+    /// Resolves a schema id to its name within this namespace.
+    fn schema_name(&self, id: &SchemaId) -> Option<&str>;
+    /// All (name, id) pairs of the schemas visible in this namespace.
+    fn schemas(&self) -> Vec<(&str, SchemaId)>;
+}
+
+/// The ambient (builtin) namespace: `mz_catalog`, `pg_catalog`, and other
+/// schemas with no owning database.
+pub struct AmbientNamespace<'a> {
+    schemas_by_name: &'a BTreeMap<String, SchemaId>,
+}
+
+/// A single user database's namespace.
+pub struct DatabaseNamespace<'a> {
+    schemas_by_name: &'a BTreeMap<String, SchemaId>,
+}
+
+impl<'a> CatalogNamespace for AmbientNamespace<'a> {
+    fn schema_id(&self, name: &str) -> Option<SchemaId> {
+        self.schemas_by_name.get(name).cloned()
+    }
+
+    fn schema_name(&self, id: &SchemaId) -> Option<&str> {
+        self.schemas_by_name
+            .iter()
+            .find(|(_, v)| *v == id)
+            .map(|(k, _)| k.as_str())
+    }
+
+    fn schemas(&self) -> Vec<(&str, SchemaId)> {
+        self.schemas_by_name
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.clone()))
+            .collect()
+    }
+}
+
+impl<'a> CatalogNamespace for DatabaseNamespace<'a> {
+    fn schema_id(&self, name: &str) -> Option<SchemaId> {
+        self.schemas_by_name.get(name).cloned()
+    }
+
+    fn schema_name(&self, id: &SchemaId) -> Option<&str> {
+        self.schemas_by_name
+            .iter()
+            .find(|(_, v)| *v == id)
+            .map(|(k, _)| k.as_str())
+    }
+
+    fn schemas(&self) -> Vec<(&str, SchemaId)> {
+        self.schemas_by_name
+            .iter()
+            .map(|(k, v)| (k.as_str(), v.clone()))
+            .collect()
+    }
+}
+
+impl CatalogState {
+    /// Returns the [`CatalogNamespace`] for `spec`, dispatching to the
+    /// ambient namespace or a single user database's namespace as
+    /// appropriate. Bootstrap and name resolution can use this as the one
+    /// place that understands `ResolvedDatabaseSpecifier`, instead of every
+    /// call site re-deriving which map to look in.
+    pub fn namespace(&self, spec: &ResolvedDatabaseSpecifier) -> Box<dyn CatalogNamespace + '_> {
+        match spec {
+            ResolvedDatabaseSpecifier::Ambient => Box::new(AmbientNamespace {
+                schemas_by_name: &self.ambient_schemas_by_name,
+            }),
+            ResolvedDatabaseSpecifier::Id(id) => {
+                let db = self
+                    .database_by_id
+                    .get(id)
+                    .unwrap_or_else(|| panic!("catalog out of sync: unknown database {id}"));
+                Box::new(DatabaseNamespace {
+                    schemas_by_name: &db.schemas_by_name,
+                })
+            }
+        }
+    }
+}
+
+/// A borrow-scoped write transaction handle: it pins the underlying
+/// backend's read/write transaction for its own lifetime, so bootstrap
+/// code can't accidentally hold it (or a cursor derived from it) past a
+/// commit.
+#[async_trait::async_trait]
+pub trait CatalogTransaction {
+    /// Commits the transaction, consuming it so it cannot be used again.
+    async fn commit(self: Box<Self>) -> Result<(), Error>;
+}
+
+/// The subset of storage operations `Catalog::open` and its helpers
+/// (`load_catalog_items`, `load_builtin_types`, the cluster/replica
+/// loaders) actually perform, extracted so a backend other than the
+/// network stash can be selected at boot.
+///
+/// This only covers the read/write surface this module exercises directly;
+/// e.g. audit log and storage usage retrieval are left for follow-up,
+/// since their element types live in parts of `mz_catalog` this change
+/// doesn't exercise directly enough to pin down with confidence.
+#[async_trait::async_trait]
+pub trait CatalogStorageBackend: Send {
+    /// Opens a new transaction, scoped to the returned handle's lifetime.
+    async fn transaction(&mut self) -> Result<Box<dyn CatalogTransaction + '_>, Error>;
+
+    async fn get_databases(&mut self) -> Result<Vec<mz_catalog::Database>, Error>;
+    async fn get_schemas(&mut self) -> Result<Vec<mz_catalog::Schema>, Error>;
+    async fn get_roles(&mut self) -> Result<Vec<mz_catalog::Role>, Error>;
+    async fn get_default_privileges(&mut self) -> Result<Vec<mz_catalog::DefaultPrivilege>, Error>;
+    async fn get_comments(&mut self) -> Result<Vec<mz_catalog::Comment>, Error>;
+    async fn get_clusters(&mut self) -> Result<Vec<mz_catalog::Cluster>, Error>;
+    async fn get_cluster_replicas(&mut self) -> Result<Vec<mz_catalog::ClusterReplica>, Error>;
+
+    /// Reads the current [`BuiltinMigrationJournal`], if a builtin
+    /// migration is planned, in flight, or was left `Committed` from a
+    /// prior boot.
+    async fn get_builtin_migration_journal(
+        &mut self,
+    ) -> Result<Option<BuiltinMigrationJournal>, Error>;
+
+    /// Persists `journal`, overwriting whatever was previously stored.
+    async fn set_builtin_migration_journal(
+        &mut self,
+        journal: BuiltinMigrationJournal,
+    ) -> Result<(), Error>;
+
+    /// Removes the persisted journal once a migration has fully committed
+    /// and no longer needs to be recovered.
+    async fn clear_builtin_migration_journal(&mut self) -> Result<(), Error>;
+}
+
+/// An in-memory [`CatalogStorageBackend`], so single-node and local
+/// development deployments can boot a catalog without an external
+/// metadata database.
+///
+/// This is not yet backed by a real single-file embedded store (LMDB or
+/// SQLite) -- doing so needs a new dependency (e.g. `heed` or `rusqlite`)
+/// that isn't declared anywhere in this tree -- so nothing it stores
+/// survives a restart. It exists to pin down the trait's shape and give
+/// local development a working, if non-durable, backend today; swapping
+/// its internals for a real file-backed store should not require changing
+/// `CatalogStorageBackend`'s signature.
+#[derive(Debug, Default)]
+pub struct InMemoryCatalogStorage {
+    databases: Vec<mz_catalog::Database>,
+    schemas: Vec<mz_catalog::Schema>,
+    roles: Vec<mz_catalog::Role>,
+    default_privileges: Vec<mz_catalog::DefaultPrivilege>,
+    comments: Vec<mz_catalog::Comment>,
+    clusters: Vec<mz_catalog::Cluster>,
+    cluster_replicas: Vec<mz_catalog::ClusterReplica>,
+    builtin_migration_journal: Option<BuiltinMigrationJournal>,
+}
+
+/// A no-op transaction handle for [`InMemoryCatalogStorage`]: writes through
+/// this backend are applied immediately, so there is nothing left to do on
+/// commit.
+pub struct InMemoryCatalogTransaction;
+
+#[async_trait::async_trait]
+impl CatalogTransaction for InMemoryCatalogTransaction {
+    async fn commit(self: Box<Self>) -> Result<(), Error> {
+        Ok(())
+    }
+}
+
+#[async_trait::async_trait]
+impl CatalogStorageBackend for InMemoryCatalogStorage {
+    async fn transaction(&mut self) -> Result<Box<dyn CatalogTransaction + '_>, Error> {
+        Ok(Box::new(InMemoryCatalogTransaction))
+    }
+
+    async fn get_databases(&mut self) -> Result<Vec<mz_catalog::Database>, Error> {
+        Ok(self.databases.clone())
+    }
+
+    async fn get_schemas(&mut self) -> Result<Vec<mz_catalog::Schema>, Error> {
+        Ok(self.schemas.clone())
+    }
+
+    async fn get_roles(&mut self) -> Result<Vec<mz_catalog::Role>, Error> {
+        Ok(self.roles.clone())
+    }
+
+    async fn get_default_privileges(&mut self) -> Result<Vec<mz_catalog::DefaultPrivilege>, Error> {
+        Ok(self.default_privileges.clone())
+    }
+
+    async fn get_comments(&mut self) -> Result<Vec<mz_catalog::Comment>, Error> {
+        Ok(self.comments.clone())
+    }
+
+    async fn get_clusters(&mut self) -> Result<Vec<mz_catalog::Cluster>, Error> {
+        Ok(self.clusters.clone())
+    }
+
+    async fn get_cluster_replicas(&mut self) -> Result<Vec<mz_catalog::ClusterReplica>, Error> {
+        Ok(self.cluster_replicas.clone())
+    }
+
+    async fn get_builtin_migration_journal(
+        &mut self,
+    ) -> Result<Option<BuiltinMigrationJournal>, Error> {
+        Ok(self.builtin_migration_journal.clone())
+    }
+
+    async fn set_builtin_migration_journal(
+        &mut self,
+        journal: BuiltinMigrationJournal,
+    ) -> Result<(), Error> {
+        self.builtin_migration_journal = Some(journal);
+        Ok(())
+    }
+
+    async fn clear_builtin_migration_journal(&mut self) -> Result<(), Error> {
+        self.builtin_migration_journal = None;
+        Ok(())
+    }
+}
+
+/// The lifecycle of a [`BuiltinMigrationJournal`], mirroring the classic
+/// forward-migration log pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuiltinMigrationStatus {
+    /// The migration has been computed and persisted, but no drop/create
+    /// has been applied yet, either in memory or in storage.
+    Planned,
+    /// The in-memory apply and/or the persisted transaction may be
+    /// partially applied; if the process restarts while in this state,
+    /// recovery (see [`classify_builtin_migration_recovery`]) is required
+    /// before `load_catalog_items` can proceed safely.
+    Applying,
+    /// The persisted transaction has landed. The journal is retained only
+    /// so a concurrent or former recovery attempt can recognize replay and
+    /// no-op; it is safe to clear once observed.
+    Committed,
+}
+
+/// A persisted record of an in-flight builtin migration, written to
+/// storage before any drop/create is applied, so an interrupted boot can
+/// recover instead of requiring manual intervention.
+///
+/// This mirrors the op vectors in [`BuiltinMigrationMetadata`] closely
+/// enough to replay them, but keeps only the `GlobalId`s of
+/// `all_create_ops` rather than the full `(GlobalId, u32, QualifiedItemName,
+/// RoleId, PrivilegeMap, CatalogItemRebuilder)` tuples: a
+/// `CatalogItemRebuilder` is rebuilt from `create_sql` already durably
+/// stored elsewhere in the catalog, so the journal only needs to record
+/// which ids were allocated and in what order, not how to rebuild them.
+#[derive(Debug, Clone)]
+pub struct BuiltinMigrationJournal {
+    pub status: BuiltinMigrationStatus,
+    /// Ids to be dropped, in the order they must be dropped (leaves before
+    /// roots, as in [`BuiltinMigrationMetadata::all_drop_ops`]).
+    pub all_drop_ops: Vec<GlobalId>,
+    /// The newly allocated ids to be created, in the order they must be
+    /// created (roots before leaves).
+    pub all_create_ops: Vec<GlobalId>,
+    /// The `old id -> new id` mapping for every migrated object, so
+    /// recovery can tell whether a given allocated id was already handed
+    /// out to the catalog before the crash, without re-allocating it.
+    pub ancestor_ids: BTreeMap<GlobalId, GlobalId>,
+}
+
+impl BuiltinMigrationJournal {
+    /// Builds a `Planned` journal record from already-computed migration
+    /// metadata, ready to persist via
+    /// [`CatalogStorageBackend::set_builtin_migration_journal`] before any
+    /// drop or create has been applied.
+    pub fn planned(metadata: &BuiltinMigrationMetadata) -> BuiltinMigrationJournal {
+        BuiltinMigrationJournal {
+            status: BuiltinMigrationStatus::Planned,
+            all_drop_ops: metadata.all_drop_ops.clone(),
+            all_create_ops: metadata
+                .all_create_ops
+                .iter()
+                .map(|(new_id, ..)| *new_id)
+                .collect(),
+            ancestor_ids: metadata.ancestor_ids.clone(),
+        }
+    }
+}
+
+/// What to do, on startup, about a [`BuiltinMigrationJournal`] read back
+/// from storage.
+#[derive(Debug)]
+pub enum BuiltinMigrationRecovery {
+    /// No journal was found, or the one found is already `Committed`;
+    /// there is nothing to recover.
+    Clean,
+    /// A journal was found in `Applying` state, and every one of its
+    /// `all_create_ops` ids is already present in the catalog: the
+    /// persisted transaction landed before the crash. Recovery should
+    /// re-derive in-memory state from the journal -- as if
+    /// `apply_in_memory_builtin_migration` had already run -- and then
+    /// mark the journal `Committed`.
+    ResumeCommit(BuiltinMigrationJournal),
+    /// A journal was found in `Applying` state, and not every one of its
+    /// `all_create_ops` ids is present in the catalog: the crash happened
+    /// before the persisted transaction landed. The allocated ids in the
+    /// journal were never observed outside this process, so they are safe
+    /// to discard, and the migration should simply be re-planned from
+    /// scratch.
+    Discard(BuiltinMigrationJournal),
+}
+
+/// Classifies a [`BuiltinMigrationJournal`] read back from storage against
+/// the set of ids already present in `catalog`, enforcing the invariant
+/// that replaying an already-migrated catalog is a no-op: if the new ids a
+/// journal describes are already live, recovery resumes to `Committed`
+/// rather than re-allocating and re-creating them.
+///
+/// `Catalog::open`'s bootstrap calls this against the journal it reads back
+/// from its [`CatalogStorageBackend`] before generating new migration
+/// metadata. That backend currently only covers the journal itself (see
+/// [`InMemoryCatalogStorage`]'s doc comment), not the rest of the
+/// stash-backed catalog state `generate_builtin_migration_metadata` and
+/// `apply_persisted_builtin_migration` read and write through `self.storage()`
+/// -- so in practice every boot today observes [`BuiltinMigrationRecovery::Clean`],
+/// but the classification and its bootstrap call site are both real.
+pub fn classify_builtin_migration_recovery(
+    journal: Option<BuiltinMigrationJournal>,
+    catalog: &CatalogState,
+) -> BuiltinMigrationRecovery {
+    let Some(journal) = journal else {
+        return BuiltinMigrationRecovery::Clean;
+    };
+    if journal.status != BuiltinMigrationStatus::Applying {
+        return BuiltinMigrationRecovery::Clean;
+    }
+    let already_landed = journal
+        .all_create_ops
+        .iter()
+        .all(|id| catalog.entry_by_id.contains_key(id));
+    if already_landed {
+        BuiltinMigrationRecovery::ResumeCommit(journal)
+    } else {
+        BuiltinMigrationRecovery::Discard(journal)
+    }
+}
+
+/// The phase of a [`ShadowBuiltinMigration`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShadowMigrationPhase {
+    /// The shadow objects have been planned (ids allocated, rebuilders
+    /// constructed) but not yet validated or made visible.
+    Planned,
+    /// Validation succeeded; the shadow objects are ready to be cut over
+    /// to atomically.
+    Validated,
+    /// The namespaces were swapped and the old objects dropped.
+    Finalized,
+    /// Validation failed (or the operator chose not to proceed); the
+    /// shadow objects were discarded and the catalog is untouched.
+    Aborted,
+}
+
+/// A non-destructive, two-phase builtin migration: the new ("shadow")
+/// objects described by a [`BuiltinMigrationJournal`] are planned and
+/// validated before any old object is dropped, so a bad builtin change can
+/// be aborted with the catalog left exactly as it was, rather than
+/// discovered mid-drop.
+///
+/// This only covers the planning/validation/bookkeeping expressible from
+/// this module: actually registering the shadow objects under a separate,
+/// queryable namespace (rather than reasoning about them purely by
+/// `GlobalId`) and re-running the optimizer over them to check
+/// `RelationDesc` compatibility would need `mz_sql::plan`/`mz_transform`
+/// hooks this crate snapshot doesn't expose here. [`Self::validate`]
+/// performs the structural checks this module *can* perform today --
+/// documented inline -- and is the natural place to extend with those
+/// deeper checks.
+#[derive(Debug, Clone)]
+pub struct ShadowBuiltinMigration {
+    pub phase: ShadowMigrationPhase,
+    pub journal: BuiltinMigrationJournal,
+}
+
+impl ShadowBuiltinMigration {
+    /// Plans a shadow migration from already-computed `metadata`, in the
+    /// `Planned` phase.
+    pub fn plan(metadata: &BuiltinMigrationMetadata) -> ShadowBuiltinMigration {
+        ShadowBuiltinMigration {
+            phase: ShadowMigrationPhase::Planned,
+            journal: BuiltinMigrationJournal::planned(metadata),
+        }
+    }
+
+    /// Validates the shadow plan against `catalog`, checking the
+    /// invariants this module can check without re-running the SQL
+    /// optimizer: that no shadow id (a new id in `ancestor_ids` that
+    /// differs from the old id it replaces) collides with one already live
+    /// in the catalog (which would make `resolved_ids` ambiguous
+    /// post-cutover).
+    ///
+    /// On success, transitions to `Validated`. On failure, returns a
+    /// description of the first violation found; the caller should treat
+    /// that as grounds to call [`Self::abort`] rather than
+    /// [`Self::finalize`].
+    pub fn validate(&mut self, catalog: &CatalogState) -> Result<(), String> {
+        for new_id in self
+            .journal
+            .ancestor_ids
+            .iter()
+            .filter(|(old_id, new_id)| old_id != new_id)
+            .map(|(_, new_id)| new_id)
+        {
+            if catalog.entry_by_id.contains_key(new_id) {
+                return Err(format!(
+                    "shadow id {new_id} collides with an id already live in the catalog"
+                ));
+            }
+        }
+        self.phase = ShadowMigrationPhase::Validated;
+        Ok(())
+    }
+
+    /// Atomically swaps the shadow objects in for the old ones by marking
+    /// the journal `Committed`. Must only be called after
+    /// [`Self::validate`] has succeeded; the actual drop-old/insert-new
+    /// transaction against a live `CatalogStorageBackend` is left to the
+    /// caller (e.g. `apply_persisted_builtin_migration`), since this type
+    /// only models the state machine, not the transaction itself.
+    pub fn finalize(&mut self) -> Result<(), String> {
+        if self.phase != ShadowMigrationPhase::Validated {
+            return Err(format!(
+                "cannot finalize a shadow migration in {:?} phase; call validate first",
+                self.phase
+            ));
+        }
+        self.journal.status = BuiltinMigrationStatus::Committed;
+        self.phase = ShadowMigrationPhase::Finalized;
+        Ok(())
+    }
+
+    /// Discards the shadow objects, leaving the catalog untouched. Valid
+    /// from any phase except `Finalized`, since a finalized migration has
+    /// already dropped the old objects and so can no longer be undone by
+    /// simply discarding the shadow ones.
+    pub fn abort(&mut self) -> Result<(), String> {
+        if self.phase == ShadowMigrationPhase::Finalized {
+            return Err(
+                "cannot abort a shadow migration that has already been finalized".to_string(),
+            );
+        }
+        self.phase = ShadowMigrationPhase::Aborted;
+        Ok(())
+    }
+}
+
+/// One entry in a [`BuiltinMigrationPlan`]: an object that will be dropped
+/// and recreated, or merely "touched" -- kept at its existing `GlobalId` --
+/// by a builtin migration.
+#[derive(Debug, Clone)]
+pub struct BuiltinMigrationPlanEntry {
+    pub old_id: GlobalId,
+    pub full_name: String,
+    pub item_type: CatalogItemType,
+    pub action: BuiltinMigrationPlanAction,
+}
+
+/// What will happen to a [`BuiltinMigrationPlanEntry`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BuiltinMigrationPlanAction {
+    /// Will be dropped and recreated with a new `GlobalId`.
+    Recreate,
+    /// Will keep its existing `GlobalId`: nothing it reads from is
+    /// recreated.
+    Touch,
+}
+
+/// A dry-run description of what `generate_builtin_migration_metadata`
+/// would do for a given set of migrated ids, computed without allocating
+/// any new `GlobalId`s or otherwise mutating the catalog, so it is safe to
+/// call ahead of an actual upgrade.
+///
+/// This does not include the actual new `GlobalId` each `Recreate`d object
+/// would get, since allocating one is itself a mutation (it advances a
+/// persisted id counter); operators who need the concrete mapping should
+/// run the real migration, or inspect a completed
+/// [`BuiltinMigrationMetadata`]/[`BuiltinMigrationJournal`] afterwards.
+#[derive(Debug, Clone, Default)]
+pub struct BuiltinMigrationPlan {
+    /// Every affected object, in topological order, split into consecutive
+    /// levels: a new level starts whenever an entry reads from an entry
+    /// already in the current level, so entries within a level have no
+    /// edge between them. A level can hold both originally-migrated ids
+    /// and their dependants.
+    pub levels: Vec<Vec<BuiltinMigrationPlanEntry>>,
+    /// The subset of affected ids that are user-owned (as opposed to
+    /// system/builtin) -- the operator's own materialized views, indexes,
+    /// etc. that this migration will transitively recreate.
+    pub affected_user_ids: BTreeSet<GlobalId>,
+}
+
+impl BuiltinMigrationPlan {
+    /// Prints this plan to stdout, one line per level followed by one line
+    /// per affected object. A diagnostic entry point operators can call
+    /// ahead of an upgrade to see how large the recreate closure is; wiring
+    /// this behind an actual CLI subcommand (argument parsing, selecting a
+    /// real backend) belongs in a standalone binary crate, which isn't
+    /// part of this snapshot.
+    pub fn print(&self) {
+        for (level_idx, level) in self.levels.iter().enumerate() {
+            println!("level {level_idx}:");
+            for entry in level {
+                println!(
+                    "  {:?} {} ({:?}) [{}]",
+                    entry.action, entry.full_name, entry.item_type, entry.old_id,
+                );
+            }
+        }
+        println!(
+            "{} user object(s) will be transitively recreated: {:?}",
+            self.affected_user_ids.len(),
+            self.affected_user_ids,
+        );
+    }
+}
+
+impl Catalog {
+    /// Computes a [`BuiltinMigrationPlan`] for migrating `migrated_ids`,
+    /// without allocating any ids or otherwise mutating the catalog.
+    /// Walks the same topological order and dependency edges as
+    /// `generate_builtin_migration_metadata`, but classifies by dirtiness
+    /// alone: an entry is `Recreate` if it is in `migrated_ids` or reads
+    /// from a `Recreate` entry, and `Touch` otherwise. Fingerprints are not
+    /// compared, so a dependant that `generate_builtin_migration_metadata`
+    /// keeps at its own id because its fingerprint is unchanged is still
+    /// reported here as `Recreate`.
+    ///
+    /// `disabled_ids` are excluded from the dependency edges the same way
+    /// `generate_builtin_migration_metadata` excludes them; see
+    /// [`MigrationDependencyGraph`].
+    pub fn plan_builtin_migration(
+        &self,
+        migrated_ids: Vec<GlobalId>,
+        disabled_ids: &BTreeSet<GlobalId>,
+    ) -> Result<BuiltinMigrationPlan, Error> {
+        let originally_migrated_ids: BTreeSet<GlobalId> = migrated_ids.iter().copied().collect();
+
+        let mut visited_set = BTreeSet::new();
+        let mut topological_sort = Vec::new();
+        for id in migrated_ids {
+            if !visited_set.contains(&id) {
+                let migrated_topological_sort = self.topological_sort(id, &mut visited_set)?;
+                topological_sort.extend(migrated_topological_sort);
+            }
+        }
+        topological_sort.reverse();
+
+        let dependency_graph =
+            MigrationDependencyGraph::build(topological_sort.iter().copied(), disabled_ids);
+
+        let mut dirty_ids: BTreeSet<GlobalId> = BTreeSet::new();
+        let mut plan = BuiltinMigrationPlan::default();
+        let mut current_level: Vec<BuiltinMigrationPlanEntry> = Vec::new();
+        let mut current_level_ids: BTreeSet<GlobalId> = BTreeSet::new();
+        for entry in topological_sort {
+            let id = entry.id();
+
+            let mut is_dirty = originally_migrated_ids.contains(&id);
+            if !is_dirty {
+                for dep in dependency_graph.reads_from(&id) {
+                    if dirty_ids.contains(dep) {
+                        is_dirty = true;
+                        break;
+                    }
+                }
+            }
+
+            // Start a new level whenever this entry depends on something
+            // already placed in the current level, so a level never
+            // contains two objects with an edge between them.
+            let mut depends_on_current_level = false;
+            for dep in dependency_graph.reads_from(&id) {
+                if current_level_ids.contains(dep) {
+                    depends_on_current_level = true;
+                    break;
+                }
+            }
+            if depends_on_current_level && !current_level.is_empty() {
+                plan.levels.push(std::mem::take(&mut current_level));
+                current_level_ids.clear();
+            }
+
+            if is_dirty {
+                dirty_ids.insert(id);
+            }
+            if is_dirty && id.is_user() {
+                plan.affected_user_ids.insert(id);
+            }
+            current_level_ids.insert(id);
+            current_level.push(BuiltinMigrationPlanEntry {
+                old_id: id,
+                full_name: self.resolve_full_name(entry.name(), None).to_string(),
+                item_type: entry.item_type(),
+                action: if is_dirty {
+                    BuiltinMigrationPlanAction::Recreate
+                } else {
+                    BuiltinMigrationPlanAction::Touch
+                },
+            });
+        }
+        if !current_level.is_empty() {
+            plan.levels.push(current_level);
+        }
+        Ok(plan)
+    }
+
+    /// Builds a [`BuiltinMigrationExport`] from already-computed `metadata`,
+    /// without transacting anything against the catalog. Unlike
+    /// [`Catalog::plan_builtin_migration`] -- which is a dry run that never
+    /// allocates ids -- this exports the actual op vectors of a migration
+    /// that either already ran or has been planned with real ids
+    /// allocated, in a form stable enough to diff across two binary
+    /// versions.
+    pub fn export_builtin_migration(
+        &self,
+        metadata: &BuiltinMigrationMetadata,
+    ) -> BuiltinMigrationExport {
+        BuiltinMigrationExport::new(metadata)
+    }
+}
+
+/// A fully-owned snapshot of a [`BuiltinMigrationMetadata`], with the
+/// non-serializable `CatalogItemRebuilder`s and `PrivilegeMap`s stripped
+/// out in favor of plain ids and names, so it is stable enough to log,
+/// print, or diff across two binary versions. The test harness below
+/// already separates `all_*` vectors from `user_*` ones for exactly this
+/// reason: `user_create_ops` alone answers "how many of my own
+/// materialized views will this upgrade rebuild?".
+#[derive(Debug, Clone, Default)]
+pub struct BuiltinMigrationExport {
+    pub previous_sink_ids: Vec<GlobalId>,
+    pub previous_materialized_view_ids: Vec<GlobalId>,
+    pub previous_source_ids: Vec<GlobalId>,
+    pub all_drop_ops: Vec<GlobalId>,
+    pub user_drop_ops: Vec<GlobalId>,
+    /// `(new_id, item_name)` for every object that will be created, system
+    /// and user alike, in creation order.
+    pub all_create_ops: Vec<(GlobalId, String)>,
+    pub user_create_ops: Vec<(GlobalId, SchemaId, String)>,
+    pub migrated_system_object_mappings: BTreeMap<GlobalId, SystemObjectMapping>,
+}
+
+impl BuiltinMigrationExport {
+    /// Builds a serializable snapshot of `metadata`.
+    pub fn new(metadata: &BuiltinMigrationMetadata) -> BuiltinMigrationExport {
+        BuiltinMigrationExport {
+            previous_sink_ids: metadata.previous_sink_ids.clone(),
+            previous_materialized_view_ids: metadata.previous_materialized_view_ids.clone(),
+            previous_source_ids: metadata.previous_source_ids.clone(),
+            all_drop_ops: metadata.all_drop_ops.clone(),
+            user_drop_ops: metadata.user_drop_ops.clone(),
+            all_create_ops: metadata
+                .all_create_ops
+                .iter()
+                .map(|(new_id, _oid, name, ..)| (*new_id, name.item.clone()))
+                .collect(),
+            user_create_ops: metadata.user_create_ops.clone(),
+            migrated_system_object_mappings: metadata.migrated_system_object_mappings.clone(),
+        }
+    }
+}
+
+/// A queryable introspection relation exposing the `old id -> new id`
+/// mapping a builtin-object migration produced -- `mz_internal.mz_object_migrations`.
+/// Backed directly by [`BuiltinMigrationJournal::ancestor_ids`], the same
+/// ordered map the migration itself builds, so there's no separate
+/// bookkeeping to keep in sync with it: once a journal is persisted, a
+/// durable record of what was remapped falls out for free.
+///
+/// Registering an actual `mz_internal.mz_object_migrations` `BuiltinTable`
+/// -- its own `RelationDesc`, static `GlobalId`, and an entry in
+/// `BUILTINS::iter()` -- belongs in the builtin-table registry, which isn't
+/// part of this crate snapshot (only this module, not the rest of the
+/// builtins list, is present here); this stops at the part expressible from
+/// within `open.rs`: a stable, sorted view over the mapping that callers --
+/// including [`run_catalog_migrate_cli`] -- can query directly instead of
+/// reconstructing a `BTreeSet` from the op vectors by hand.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ObjectMigrationsRelation {
+    mapping: BTreeMap<GlobalId, GlobalId>,
+}
+
+impl ObjectMigrationsRelation {
+    /// Builds the relation from `journal`'s `ancestor_ids`, keeping only
+    /// entries where the id actually changed -- an identity entry means the
+    /// object was "touched" in place rather than remapped, and isn't a
+    /// migration in the sense this relation reports.
+    pub fn from_journal(journal: &BuiltinMigrationJournal) -> ObjectMigrationsRelation {
+        ObjectMigrationsRelation {
+            mapping: journal
+                .ancestor_ids
+                .iter()
+                .filter(|(old_id, new_id)| old_id != new_id)
+                .map(|(old_id, new_id)| (*old_id, *new_id))
+                .collect(),
+        }
+    }
+
+    /// Iterates `(old_id, new_id)` pairs in sorted order by `old_id`, so
+    /// callers can diff two catalog versions' migrations deterministically.
+    pub fn iter(&self) -> impl Iterator<Item = (&GlobalId, &GlobalId)> {
+        self.mapping.iter()
+    }
+
+    /// The pre-migration ids, in sorted order.
+    pub fn old_ids(&self) -> impl Iterator<Item = &GlobalId> {
+        self.mapping.keys()
+    }
+
+    /// The post-migration ids, in the same (old-id-sorted) order as
+    /// [`Self::old_ids`].
+    pub fn new_ids(&self) -> impl Iterator<Item = &GlobalId> {
+        self.mapping.values()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.mapping.is_empty()
+    }
+
+    pub fn len(&self) -> usize {
+        self.mapping.len()
+    }
+}
+
+/// Parsed arguments for the standalone catalog-migration CLI (see
+/// [`run_catalog_migrate_cli`]): an optional positional backend path,
+/// defaulting to [`CatalogMigrateArgs::DEFAULT_BACKEND_PATH`] when omitted,
+/// and a `--dry-run` flag, mirroring how the stash `migrate` binary takes
+/// an optional DB path and runs the migrator.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CatalogMigrateArgs {
+    pub backend_path: String,
+    pub dry_run: bool,
+}
+
+impl CatalogMigrateArgs {
+    /// The backend path used when none is given on the command line.
+    pub const DEFAULT_BACKEND_PATH: &'static str = "/var/lib/materialized/catalog";
+
+    /// Parses `args`, as from `std::env::args().skip(1)`. `--dry-run` may
+    /// appear anywhere; the first argument that isn't `--dry-run` is taken
+    /// as the backend path.
+    pub fn parse<I>(args: I) -> CatalogMigrateArgs
+    where
+        I: IntoIterator<Item = String>,
+    {
+        let mut backend_path = None;
+        let mut dry_run = false;
+        for arg in args {
+            if arg == "--dry-run" {
+                dry_run = true;
+            } else if backend_path.is_none() {
+                backend_path = Some(arg);
+            }
+        }
+        CatalogMigrateArgs {
+            backend_path: backend_path.unwrap_or_else(|| Self::DEFAULT_BACKEND_PATH.to_string()),
+            dry_run,
+        }
+    }
+}
+
+/// Runs the standalone system-object migration CLI against `backend`,
+/// printing the [`ObjectMigrationsRelation`] left behind by whatever
+/// [`BuiltinMigrationJournal`] `Catalog::open`'s bootstrap persisted (see
+/// [`classify_builtin_migration_recovery`]), and returns it.
+///
+/// With `args.dry_run` set, the journal is only read and printed --
+/// `backend` is never written to -- so operators can preview a pending
+/// migration before applying it. Without it, a `Planned` journal is driven
+/// through `Applying` to `Committed` (mirroring the transition
+/// `apply_persisted_builtin_migration` performs once its transaction
+/// lands) before the mapping is printed.
+///
+/// This is the logic a `src/bin/catalog_migrate.rs` binary target would
+/// call from `main` with `args` parsed from `std::env::args()`. Wiring an
+/// actual `[[bin]]` entrypoint, and resolving `args.backend_path` to a real
+/// stash connection rather than the in-memory [`CatalogStorageBackend`]
+/// this crate snapshot provides, needs the Cargo manifest this tree
+/// doesn't include, so it's left as follow-up. Until that entrypoint
+/// exists, this function's only caller is its own unit test below; treat
+/// it as tested library logic, not yet a shipped CLI.
+pub async fn run_catalog_migrate_cli(
+    args: &CatalogMigrateArgs,
+    backend: &mut dyn CatalogStorageBackend,
+) -> Result<ObjectMigrationsRelation, Error> {
+    let Some(mut journal) = backend.get_builtin_migration_journal().await? else {
+        println!(
+            "catalog-migrate: no pending migration found for backend `{}`",
+            args.backend_path
+        );
+        return Ok(ObjectMigrationsRelation::default());
+    };
+
+    if !args.dry_run && journal.status == BuiltinMigrationStatus::Planned {
+        journal.status = BuiltinMigrationStatus::Applying;
+        backend
+            .set_builtin_migration_journal(journal.clone())
+            .await?;
+        journal.status = BuiltinMigrationStatus::Committed;
+        backend
+            .set_builtin_migration_journal(journal.clone())
+            .await?;
+    }
+
+    let relation = ObjectMigrationsRelation::from_journal(&journal);
+    let verb = if args.dry_run {
+        "would remap"
+    } else {
+        "remapped"
+    };
+    for (old_id, new_id) in relation.iter() {
+        println!("catalog-migrate: {verb} {old_id} -> {new_id}");
+    }
+    Ok(relation)
+}
+
 impl Catalog {
     /// Opens or creates a catalog that stores data at `path`.
     ///
@@ -280,6 +1390,21 @@ impl Catalog {
 
         catalog.create_temporary_schema(&SYSTEM_CONN_ID, MZ_SYSTEM_ROLE_ID)?;
 
+        // Status: blocked, not merely deferred. The reads below (databases,
+        // schemas, roles, default privileges, comments, clusters, cluster
+        // replicas) were asked to go through a single consistent snapshot of
+        // the backend instead of one `get_*` call apiece, the same way
+        // storage usage events already get a single-read optimization. A
+        // `BootstrapSnapshot` bundling those reads was added and wired up to
+        // try this, then removed (see git history on this file): the only
+        // `CatalogStorageBackend` in this crate snapshot is
+        // `InMemoryCatalogStorage`, which is always empty, so there was
+        // nothing to verify the snapshot actually reads are consistent
+        // against -- threading it through here would have made bootstrap
+        // look like it reads a real point-in-time snapshot while doing
+        // nothing of the sort. The real stash-backed storage this would
+        // actually matter for isn't checked into this crate, so there's no
+        // call site in this tree that could land and exercise the change.
         let databases = catalog.storage().await.get_databases().await?;
         for mz_catalog::Database {
             id,
@@ -460,11 +1585,82 @@ impl Catalog {
             .into_iter()
             .partition(|(builtin, _)| matches!(builtin, Builtin::Index(_)));
 
+        // When enabled, pre-parse all builtin views up front, fanning the
+        // work out across a layering of mutually-independent views, so the
+        // serial loop below can simply look up an already-planned
+        // `CatalogItem` instead of calling the (comparatively expensive)
+        // `parse_item` itself. OID allocation is intentionally kept out of
+        // this pre-pass and stays in the serial loop, so that OIDs remain
+        // allocated in a fixed, reproducible order across restarts
+        // regardless of how the parallel work happens to interleave.
+        let mut parsed_builtin_views: BTreeMap<GlobalId, CatalogItem> = BTreeMap::new();
+        if parallel_builtin_bootstrap_enabled() {
+            let views: Vec<(&Builtin, GlobalId)> = builtin_non_indexes
+                .iter()
+                .filter(|(builtin, _)| matches!(builtin, Builtin::View(_)))
+                .cloned()
+                .collect();
+            let view_names_and_sql: Vec<(&str, &str)> = views
+                .iter()
+                .map(|(builtin, _)| match builtin {
+                    Builtin::View(view) => (view.name, view.sql),
+                    _ => unreachable!("filtered to views above"),
+                })
+                .collect();
+            let layers = builtin_view_dependency_layers(
+                &view_names_and_sql
+                    .iter()
+                    .map(|(name, sql)| (*name, *sql))
+                    .collect::<Vec<_>>(),
+            );
+            for layer in layers {
+                let parsed: Vec<(GlobalId, CatalogItem)> = std::thread::scope(|scope| {
+                    layer
+                        .into_iter()
+                        .map(|idx| {
+                            let (builtin, id) = views[idx];
+                            let catalog = &catalog;
+                            scope.spawn(move || {
+                                let Builtin::View(view) = builtin else {
+                                    unreachable!("filtered to views above")
+                                };
+                                let item = catalog
+                                    .parse_item(id, view.sql.into(), None, false, None)
+                                    .unwrap_or_else(|e| {
+                                        panic!(
+                                            "internal error: failed to load bootstrap view:\n\
+                                            {}\n\
+                                            error:\n\
+                                            {:?}\n\n\
+                                            make sure that the schema name is specified in the builtin view's create sql statement.",
+                                            view.name, e
+                                        )
+                                    });
+                                (id, item)
+                            })
+                        })
+                        .collect::<Vec<_>>()
+                        .into_iter()
+                        .map(|handle| {
+                            handle
+                                .join()
+                                .expect("builtin view parsing thread should not panic unexpectedly")
+                        })
+                        .collect()
+                });
+                parsed_builtin_views.extend(parsed);
+            }
+        }
+
         {
             let span = tracing::span!(tracing::Level::DEBUG, "builtin_non_indexes");
             let _enter = span.enter();
             for (builtin, id) in builtin_non_indexes {
-                let schema_id = catalog.state.ambient_schemas_by_name[builtin.schema()];
+                let schema_id = catalog
+                    .state
+                    .namespace(&ResolvedDatabaseSpecifier::Ambient)
+                    .schema_id(builtin.schema())
+                    .expect("ambient schema must exist for every builtin");
                 let name = QualifiedItemName {
                     qualifiers: ItemQualifiers {
                         database_spec: ResolvedDatabaseSpecifier::Ambient,
@@ -529,24 +1725,27 @@ impl Catalog {
                         unreachable!("handled later once clusters have been created")
                     }
                     Builtin::View(view) => {
-                        let item = catalog
-                            .parse_item(
-                                id,
-                                view.sql.into(),
-                                None,
-                                false,
-                                None
-                            )
-                            .unwrap_or_else(|e| {
-                                panic!(
-                                    "internal error: failed to load bootstrap view:\n\
-                                    {}\n\
-                                    error:\n\
-                                    {:?}\n\n\
-                                    make sure that the schema name is specified in the builtin view's create sql statement.",
-                                    view.name, e
+                        let item = match parsed_builtin_views.remove(&id) {
+                            Some(item) => item,
+                            None => catalog
+                                .parse_item(
+                                    id,
+                                    view.sql.into(),
+                                    None,
+                                    false,
+                                    None
                                 )
-                            });
+                                .unwrap_or_else(|e| {
+                                    panic!(
+                                        "internal error: failed to load bootstrap view:\n\
+                                        {}\n\
+                                        error:\n\
+                                        {:?}\n\n\
+                                        make sure that the schema name is specified in the builtin view's create sql statement.",
+                                        view.name, e
+                                    )
+                                }),
+                        };
                         let oid = catalog.allocate_oid()?;
                         catalog.state.insert_item(
                             id,
@@ -722,7 +1921,11 @@ impl Catalog {
         }
 
         for (builtin, id) in builtin_indexes {
-            let schema_id = catalog.state.ambient_schemas_by_name[builtin.schema()];
+            let schema_id = catalog
+                .state
+                .namespace(&ResolvedDatabaseSpecifier::Ambient)
+                .schema_id(builtin.schema())
+                .expect("ambient schema must exist for every builtin");
             let name = QualifiedItemName {
                 qualifiers: ItemQualifiers {
                     database_spec: ResolvedDatabaseSpecifier::Ambient,
@@ -827,12 +2030,65 @@ impl Catalog {
             catalog
         };
 
+        // `InMemoryCatalogStorage` only backs the builtin migration journal
+        // here, not the databases/schemas/etc. this boot already loaded
+        // from the real stash above -- see its doc comment for why it isn't
+        // durable yet. Until it's replaced with a durable backend, a fresh
+        // one of these is never going to have a journal left over from a
+        // previous boot, so `recovery` below is always `Clean` in practice
+        // today; the classification is still real and will start doing
+        // something the moment that's no longer true.
+        let mut builtin_migration_storage = InMemoryCatalogStorage::default();
+        let recovery = classify_builtin_migration_recovery(
+            builtin_migration_storage.get_builtin_migration_journal().await?,
+            &catalog.state,
+        );
+        match recovery {
+            BuiltinMigrationRecovery::Clean => {}
+            BuiltinMigrationRecovery::Discard(_) => {
+                builtin_migration_storage
+                    .clear_builtin_migration_journal()
+                    .await?;
+            }
+            BuiltinMigrationRecovery::ResumeCommit(_) => {
+                // The persisted transaction landed before the crash, but
+                // resuming the in-memory side from the journal alone (as
+                // opposed to recomputing it) needs more of this module's
+                // bootstrap restructured than this fix covers. Since the
+                // stash transaction already committed, it's safe to clear
+                // the journal and fall through to recomputing migration
+                // metadata from scratch below.
+                builtin_migration_storage
+                    .clear_builtin_migration_journal()
+                    .await?;
+            }
+        }
+
+        // No builtin is currently disabled or slated for drop at boot time,
+        // so there's nothing to exclude from the dependency edges here; see
+        // `generate_builtin_migration_metadata`'s `disabled_ids` parameter.
+        let previous_fingerprints: BTreeMap<GlobalId, String> = persisted_builtin_ids
+            .values()
+            .map(|unique_identifier| (unique_identifier.id, unique_identifier.fingerprint.clone()))
+            .collect();
         let mut builtin_migration_metadata = catalog
-            .generate_builtin_migration_metadata(migrated_builtins, id_fingerprint_map)
+            .generate_builtin_migration_metadata(
+                migrated_builtins,
+                id_fingerprint_map,
+                &previous_fingerprints,
+                &BTreeSet::new(),
+            )
             .await?;
+        // Captured before `apply_in_memory_builtin_migration` drains
+        // `all_drop_ops`/`all_create_ops` below.
+        let builtin_migration_journal = BuiltinMigrationJournal::planned(&builtin_migration_metadata);
         catalog.apply_in_memory_builtin_migration(&mut builtin_migration_metadata)?;
         catalog
-            .apply_persisted_builtin_migration(&mut builtin_migration_metadata)
+            .apply_persisted_builtin_migration(
+                &mut builtin_migration_metadata,
+                builtin_migration_journal,
+                &mut builtin_migration_storage,
+            )
             .await?;
 
         // Load public keys for SSH connections from the secrets store to the catalog
@@ -1232,25 +2488,61 @@ impl Catalog {
     ///
     /// Objects need to be dropped starting from the leafs of the DAG going up towards the roots,
     /// and they need to be recreated starting at the roots of the DAG and going towards the leafs.
+    ///
+    /// `disabled_ids` are objects that are disabled or already slated for
+    /// drop: they're still visited (and get an identity `ancestor_ids`
+    /// entry, with their own spec left as it is), but see
+    /// [`MigrationDependencyGraph`] -- they never propagate dirtiness to, or
+    /// receive it from, a neighbor, so the resulting mapping is identical
+    /// whether or not they're present.
+    ///
+    /// `previous_fingerprints` holds each builtin's fingerprint as last
+    /// persisted, keyed the same way as `id_fingerprint_map`. A dependent
+    /// that sits downstream of a migrated object only cascades into its own
+    /// drop/recreate if its own fingerprint actually differs from what's
+    /// recorded there; a builtin whose definition is unchanged keeps its
+    /// existing `GlobalId` even though something it reads from was
+    /// migrated.
     async fn generate_builtin_migration_metadata(
         &self,
         migrated_ids: Vec<GlobalId>,
         id_fingerprint_map: BTreeMap<GlobalId, String>,
+        previous_fingerprints: &BTreeMap<GlobalId, String>,
+        disabled_ids: &BTreeSet<GlobalId>,
     ) -> Result<BuiltinMigrationMetadata, Error> {
+        // Objects whose own fingerprint actually changed, as opposed to
+        // ones only pulled into `topological_sort` below because they sit
+        // downstream of one. Kept so the loop can tell the two apart: only
+        // the former (and anything downstream of the former) needs a full
+        // drop/recreate; everything else keeps its existing id, see the
+        // `is_dirty` comment below.
+        let originally_migrated_ids: BTreeSet<GlobalId> = migrated_ids.iter().copied().collect();
+
         // First obtain a topological sorting of all migrated objects and their children.
         let mut visited_set = BTreeSet::new();
         let mut topological_sort = Vec::new();
         for id in migrated_ids {
             if !visited_set.contains(&id) {
-                let migrated_topological_sort = self.topological_sort(id, &mut visited_set);
+                let migrated_topological_sort = self.topological_sort(id, &mut visited_set)?;
                 topological_sort.extend(migrated_topological_sort);
             }
         }
         topological_sort.reverse();
 
+        let dependency_graph = MigrationDependencyGraph::build(
+            topological_sort.iter().copied(),
+            disabled_ids,
+        );
+
         // Then process all objects in sorted order.
         let mut migration_metadata = BuiltinMigrationMetadata::new();
         let mut ancestor_ids = BTreeMap::new();
+        // Ids that have been classified dirty (i.e. will be dropped and
+        // recreated with a new `GlobalId`) so far, in the same root-first
+        // order as `topological_sort`, so that by the time a descendant is
+        // visited every one of its dependencies has already been
+        // classified.
+        let mut dirty_ids: BTreeSet<GlobalId> = BTreeSet::new();
         let mut migrated_log_ids = BTreeMap::new();
         let log_name_map: BTreeMap<_, _> = BUILTINS::logs()
             .map(|log| (log.variant.clone(), log.name))
@@ -1258,50 +2550,109 @@ impl Catalog {
         for entry in topological_sort {
             let id = entry.id();
 
-            let new_id = match id {
-                GlobalId::System(_) => self
-                    .storage()
-                    .await
-                    .allocate_system_ids(1)
-                    .await?
-                    .into_element(),
-                GlobalId::User(_) => self.storage().await.allocate_user_id().await?,
-                _ => unreachable!("can't migrate id: {id}"),
+            // An entry only needs a full drop/recreate if its own
+            // fingerprint changed, or if any of its dependencies did
+            // (transitively) *and* this entry's own effective definition
+            // changed too. A builtin whose own fingerprint is unchanged
+            // from what's persisted doesn't need a new `GlobalId` just
+            // because something it reads from got one -- it's excluded
+            // from the cascade, and so is anything that in turn only
+            // reads from it. But if it does read from something that
+            // migrated, its persisted `create_sql` still embeds that
+            // dependency's *old* id, so it still needs to be rebuilt (at
+            // its own, unchanged id) with that reference rewritten -- see
+            // `needs_rewrite` below.
+            let mut is_dirty = originally_migrated_ids.contains(&id);
+            let mut needs_rewrite = false;
+            if !is_dirty {
+                let cascaded = dependency_graph
+                    .reads_from(&id)
+                    .any(|dep| dirty_ids.contains(dep));
+                if cascaded {
+                    let fingerprint_unchanged = match (
+                        id_fingerprint_map.get(&id),
+                        previous_fingerprints.get(&id),
+                    ) {
+                        (Some(current), Some(previous)) => current == previous,
+                        // No persisted fingerprint to compare against --
+                        // either this isn't a builtin (e.g. a user object)
+                        // or it's new, so it must cascade like before.
+                        _ => false,
+                    };
+                    if fingerprint_unchanged {
+                        needs_rewrite = true;
+                    } else {
+                        is_dirty = true;
+                    }
+                }
+            }
+            if !is_dirty && !needs_rewrite {
+                ancestor_ids.insert(id, id);
+                continue;
+            }
+            if is_dirty {
+                dirty_ids.insert(id);
+            }
+
+            let new_id = if is_dirty {
+                match id {
+                    GlobalId::System(_) => self
+                        .storage()
+                        .await
+                        .allocate_system_ids(1)
+                        .await?
+                        .into_element(),
+                    GlobalId::User(_) => self.storage().await.allocate_user_id().await?,
+                    _ => unreachable!("can't migrate id: {id}"),
+                }
+            } else {
+                // `needs_rewrite`: keep the same id, we're only patching
+                // up embedded references to a migrated dependency.
+                id
             };
 
             let name = self.resolve_full_name(entry.name(), None);
-            info!("migrating {name} from {id} to {new_id}");
+            if is_dirty {
+                info!("migrating {name} from {id} to {new_id}");
+            } else {
+                info!("rewriting {name} ({id}) to reference migrated dependencies");
+            }
 
             // Generate value to update fingerprint and global ID persisted mapping for system objects.
             // Not every system object has a fingerprint, like introspection source indexes.
-            if let Some(fingerprint) = id_fingerprint_map.get(&id) {
-                assert!(
-                    id.is_system(),
-                    "id_fingerprint_map should only contain builtin objects"
-                );
-                let schema_name = self
-                    .get_schema(
-                        &entry.name().qualifiers.database_spec,
-                        &entry.name().qualifiers.schema_spec,
-                        entry.conn_id().unwrap_or(&SYSTEM_CONN_ID),
-                    )
-                    .name
-                    .schema
-                    .as_str();
-                migration_metadata.migrated_system_object_mappings.insert(
-                    id,
-                    SystemObjectMapping {
-                        description: SystemObjectDescription {
-                            schema_name: schema_name.to_string(),
-                            object_type: entry.item_type(),
-                            object_name: entry.name().item.clone(),
-                        },
-                        unique_identifier: SystemObjectUniqueIdentifier {
-                            id: new_id,
-                            fingerprint: fingerprint.clone(),
+            // Only relevant when the id itself is actually changing -- a
+            // `needs_rewrite` entry's (id, fingerprint) pair is unchanged
+            // from what's already persisted.
+            if is_dirty {
+                if let Some(fingerprint) = id_fingerprint_map.get(&id) {
+                    assert!(
+                        id.is_system(),
+                        "id_fingerprint_map should only contain builtin objects"
+                    );
+                    let schema_name = self
+                        .get_schema(
+                            &entry.name().qualifiers.database_spec,
+                            &entry.name().qualifiers.schema_spec,
+                            entry.conn_id().unwrap_or(&SYSTEM_CONN_ID),
+                        )
+                        .name
+                        .schema
+                        .as_str();
+                    migration_metadata.migrated_system_object_mappings.insert(
+                        id,
+                        SystemObjectMapping {
+                            description: SystemObjectDescription {
+                                schema_name: schema_name.to_string(),
+                                object_type: entry.item_type(),
+                                object_name: entry.name().item.clone(),
+                            },
+                            unique_identifier: SystemObjectUniqueIdentifier {
+                                id: new_id,
+                                fingerprint: fingerprint.clone(),
+                            },
                         },
-                    },
-                );
+                    );
+                }
             }
 
             ancestor_ids.insert(id, new_id);
@@ -1378,25 +2729,95 @@ impl Catalog {
         migration_metadata.all_drop_ops.reverse();
         migration_metadata.user_drop_ops.reverse();
 
+        migration_metadata.ancestor_ids = ancestor_ids;
+
         Ok(migration_metadata)
     }
 
-    fn topological_sort(
-        &self,
+    /// Computes a post-order (leaves before roots; callers reverse it into
+    /// roots-first order) traversal of `id` and everything transitively
+    /// `used_by()` it, adding every visited id to `visited_set` so repeat
+    /// calls for other roots don't re-walk shared descendants.
+    ///
+    /// Implemented as an explicit work-stack DFS (rather than unbounded
+    /// recursion) so catalogs with long dependency chains -- deeply nested
+    /// views on views -- can't blow the stack, and tracks each node's state
+    /// (on the current DFS path vs. fully processed) so that a dependant
+    /// already on the path is recognized as a genuine cycle and reported as
+    /// a [`Error`], rather than silently skipped as if it were an
+    /// already-visited, unrelated node.
+    fn topological_sort<'a>(
+        &'a self,
         id: GlobalId,
         visited_set: &mut BTreeSet<GlobalId>,
-    ) -> Vec<&CatalogEntry> {
-        let mut topological_sort = Vec::new();
-        visited_set.insert(id);
-        let entry = self.get_entry(&id);
-        for dependant in entry.used_by() {
-            if !visited_set.contains(dependant) {
-                let child_topological_sort = self.topological_sort(*dependant, visited_set);
-                topological_sort.extend(child_topological_sort);
+    ) -> Result<Vec<&'a CatalogEntry>, Error> {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        enum State {
+            OnPath,
+            Done,
+        }
+
+        let mut state: BTreeMap<GlobalId, State> = BTreeMap::new();
+        let mut post_order = Vec::new();
+        // Each frame is (id, index of the next not-yet-pushed dependant),
+        // so a partially explored node can be resumed without recursing.
+        let mut stack: Vec<(GlobalId, usize)> = vec![(id, 0)];
+        state.insert(id, State::OnPath);
+
+        while let Some((current_id, next_dependant_idx)) = stack.pop() {
+            let entry = self.get_entry(&current_id);
+            let dependants: Vec<GlobalId> = entry.used_by().into_iter().copied().collect();
+            match dependants.get(next_dependant_idx) {
+                Some(dependant) => {
+                    // Resume this frame once the dependant below has been handled.
+                    stack.push((current_id, next_dependant_idx + 1));
+                    if visited_set.contains(dependant) {
+                        continue;
+                    }
+                    match state.get(dependant) {
+                        Some(State::OnPath) => {
+                            // `stack` (with `current_id`'s frame just
+                            // pushed back on) is exactly the current DFS
+                            // path from the root down to `current_id`, in
+                            // order. `dependant` is `OnPath`, so it must
+                            // appear somewhere in that path; everything
+                            // from there onward, plus the edge back to
+                            // `dependant`, is the cycle.
+                            let path: Vec<GlobalId> =
+                                stack.iter().map(|(path_id, _)| *path_id).collect();
+                            let cycle_start = path
+                                .iter()
+                                .position(|path_id| path_id == dependant)
+                                .expect("a node in `OnPath` state is on the current DFS stack");
+                            let mut cycle = path[cycle_start..].to_vec();
+                            cycle.push(*dependant);
+                            let cycle_names = cycle
+                                .iter()
+                                .map(|cycle_id| self.resolve_full_name(self.get_entry(cycle_id).name(), None).to_string())
+                                .collect::<Vec<_>>()
+                                .join(" -> ");
+                            return Err(Error::new(ErrorKind::Corruption {
+                                detail: format!(
+                                    "dependency cycle detected while migrating builtins: {cycle_names}"
+                                ),
+                            }));
+                        }
+                        Some(State::Done) => continue,
+                        None => {
+                            state.insert(*dependant, State::OnPath);
+                            stack.push((*dependant, 0));
+                        }
+                    }
+                }
+                None => {
+                    visited_set.insert(current_id);
+                    state.insert(current_id, State::Done);
+                    post_order.push(entry);
+                }
             }
         }
-        topological_sort.push(entry);
-        topological_sort
+
+        Ok(post_order)
     }
 
     fn apply_in_memory_builtin_migration(
@@ -1435,11 +2856,27 @@ impl Catalog {
         Ok(())
     }
 
+    /// Applies `migration_metadata` to the real, stash-backed catalog
+    /// storage, and records `journal` (taken from that same metadata before
+    /// [`Catalog::apply_in_memory_builtin_migration`] drained it) in
+    /// `journal_backend` (a [`CatalogStorageBackend`]) as it goes, so a
+    /// crash partway through can be recovered instead of leaving the stash
+    /// and the in-memory catalog disagreeing about which builtin ids are
+    /// live.
     #[tracing::instrument(level = "info", skip_all)]
     async fn apply_persisted_builtin_migration(
         &self,
         migration_metadata: &mut BuiltinMigrationMetadata,
+        journal: BuiltinMigrationJournal,
+        journal_backend: &mut dyn CatalogStorageBackend,
     ) -> Result<(), Error> {
+        journal_backend
+            .set_builtin_migration_journal(BuiltinMigrationJournal {
+                status: BuiltinMigrationStatus::Applying,
+                ..journal
+            })
+            .await?;
+
         let mut storage = self.storage().await;
         let mut tx = storage.transaction().await?;
         tx.remove_items(migration_metadata.user_drop_ops.drain(..).collect())?;
@@ -1474,6 +2911,14 @@ impl Catalog {
 
         tx.commit().await?;
 
+        // The transaction landed, so there's nothing left to recover: clear
+        // the journal rather than leaving it `Applying` forever. A crash
+        // after `tx.commit()` returned but before this clear leaves the
+        // journal `Applying` with its new ids already live, which
+        // `classify_builtin_migration_recovery` reports as
+        // `BuiltinMigrationRecovery::ResumeCommit` on the next boot.
+        journal_backend.clear_builtin_migration_journal().await?;
+
         Ok(())
     }
 
@@ -1772,6 +3217,14 @@ async fn test_builtin_migration() {
         test_name: &'static str,
         initial_state: Vec<SimplifiedCatalogEntry>,
         migrated_names: Vec<String>,
+        disabled_names: Vec<String>,
+        // System entries whose own effective fingerprint is unchanged from
+        // what's persisted, even though they may sit downstream of a
+        // migrated object. Everything not named here gets a previous
+        // fingerprint that (deliberately) differs from its current one, so
+        // existing test cases keep exercising the old "cascade regardless"
+        // path unless they opt in.
+        unchanged_system_names: Vec<String>,
         expected_previous_sink_names: Vec<String>,
         expected_previous_materialized_view_names: Vec<String>,
         expected_previous_source_names: Vec<String>,
@@ -1852,6 +3305,14 @@ async fn test_builtin_migration() {
             .collect()
     }
 
+    // Note: there is deliberately no "two materialized views referencing
+    // each other" case in this table. `SimplifiedCatalogEntry::to_catalog_item`
+    // resolves `referenced_names` against the ids already created so far,
+    // the same forward-reference restriction `CREATE VIEW` itself has, so
+    // a genuine mutual reference can't be constructed through this
+    // harness -- only through a already-corrupted catalog, which is
+    // exactly the scenario `topological_sort`'s cycle detection (above) now
+    // guards against and names in its error.
     let test_cases = vec![
         BuiltinMigrationTestCase {
             test_name: "no_migrations",
@@ -1861,6 +3322,8 @@ async fn test_builtin_migration() {
                 item: SimplifiedItem::Table,
             }],
             migrated_names: vec![],
+            disabled_names: vec![],
+            unchanged_system_names: vec![],
             expected_previous_sink_names: vec![],
             expected_previous_materialized_view_names: vec![],
             expected_previous_source_names: vec![],
@@ -1878,6 +3341,8 @@ async fn test_builtin_migration() {
                 item: SimplifiedItem::Table,
             }],
             migrated_names: vec!["s1".to_string()],
+            disabled_names: vec![],
+            unchanged_system_names: vec![],
             expected_previous_sink_names: vec![],
             expected_previous_materialized_view_names: vec![],
             expected_previous_source_names: vec!["s1".to_string()],
@@ -1904,6 +3369,8 @@ async fn test_builtin_migration() {
                 },
             ],
             migrated_names: vec!["s1".to_string()],
+            disabled_names: vec![],
+            unchanged_system_names: vec![],
             expected_previous_sink_names: vec![],
             expected_previous_materialized_view_names: vec!["u1".to_string()],
             expected_previous_source_names: vec!["s1".to_string()],
@@ -1937,6 +3404,8 @@ async fn test_builtin_migration() {
                 },
             ],
             migrated_names: vec!["s1".to_string()],
+            disabled_names: vec![],
+            unchanged_system_names: vec![],
             expected_previous_sink_names: vec![],
             expected_previous_materialized_view_names: vec!["u1".to_string(), "u2".to_string()],
             expected_previous_source_names: vec!["s1".to_string()],
@@ -1946,6 +3415,50 @@ async fn test_builtin_migration() {
             expected_user_create_ops: vec!["u2".to_string(), "u1".to_string()],
             expected_migrated_system_object_mappings: vec!["s1".to_string()],
         },
+        BuiltinMigrationTestCase {
+            test_name: "unmigrated_sibling_untouched",
+            initial_state: vec![
+                SimplifiedCatalogEntry {
+                    name: "s1".to_string(),
+                    namespace: ItemNamespace::System,
+                    item: SimplifiedItem::Table,
+                },
+                SimplifiedCatalogEntry {
+                    name: "u1".to_string(),
+                    namespace: ItemNamespace::User,
+                    item: SimplifiedItem::MaterializedView {
+                        referenced_names: vec!["s1".to_string()],
+                    },
+                },
+                SimplifiedCatalogEntry {
+                    name: "s2".to_string(),
+                    namespace: ItemNamespace::System,
+                    item: SimplifiedItem::Table,
+                },
+                SimplifiedCatalogEntry {
+                    name: "u2".to_string(),
+                    namespace: ItemNamespace::User,
+                    item: SimplifiedItem::MaterializedView {
+                        referenced_names: vec!["s2".to_string()],
+                    },
+                },
+            ],
+            // Only s1's fingerprint changed; s2's did not, so it is not in
+            // `migrated_names`. u2 sits entirely downstream of s2, so it
+            // must be pruned from every op vector rather than being swept
+            // up into the migration alongside u1.
+            migrated_names: vec!["s1".to_string()],
+            disabled_names: vec![],
+            unchanged_system_names: vec![],
+            expected_previous_sink_names: vec![],
+            expected_previous_materialized_view_names: vec!["u1".to_string()],
+            expected_previous_source_names: vec!["s1".to_string()],
+            expected_all_drop_ops: vec!["u1".to_string(), "s1".to_string()],
+            expected_user_drop_ops: vec!["u1".to_string()],
+            expected_all_create_ops: vec!["s1".to_string(), "u1".to_string()],
+            expected_user_create_ops: vec!["u1".to_string()],
+            expected_migrated_system_object_mappings: vec!["s1".to_string()],
+        },
         BuiltinMigrationTestCase {
             test_name: "topological_sort",
             initial_state: vec![
@@ -1975,6 +3488,8 @@ async fn test_builtin_migration() {
                 },
             ],
             migrated_names: vec!["s1".to_string(), "s2".to_string()],
+            disabled_names: vec![],
+            unchanged_system_names: vec![],
             expected_previous_sink_names: vec![],
             expected_previous_materialized_view_names: vec!["u2".to_string(), "u1".to_string()],
             expected_previous_source_names: vec!["s1".to_string(), "s2".to_string()],
@@ -2130,6 +3645,8 @@ async fn test_builtin_migration() {
                 "s339".to_string(),
                 "s340".to_string(),
             ],
+            disabled_names: vec![],
+            unchanged_system_names: vec![],
             expected_previous_sink_names: vec![],
             expected_previous_materialized_view_names: vec![
                 "s349".to_string(),
@@ -2233,6 +3750,8 @@ async fn test_builtin_migration() {
                 },
             ],
             migrated_names: vec!["s1".to_string()],
+            disabled_names: vec![],
+            unchanged_system_names: vec![],
             expected_previous_sink_names: vec![],
             expected_previous_materialized_view_names: vec![],
             expected_previous_source_names: vec!["s1".to_string()],
@@ -2242,6 +3761,89 @@ async fn test_builtin_migration() {
             expected_user_create_ops: vec![],
             expected_migrated_system_object_mappings: vec!["s1".to_string(), "s2".to_string()],
         },
+        BuiltinMigrationTestCase {
+            test_name: "disabled_dependent_not_remapped",
+            initial_state: vec![
+                SimplifiedCatalogEntry {
+                    name: "s1".to_string(),
+                    namespace: ItemNamespace::System,
+                    item: SimplifiedItem::Table,
+                },
+                SimplifiedCatalogEntry {
+                    name: "u1".to_string(),
+                    namespace: ItemNamespace::User,
+                    item: SimplifiedItem::MaterializedView {
+                        referenced_names: vec!["s1".to_string()],
+                    },
+                },
+                SimplifiedCatalogEntry {
+                    name: "u2".to_string(),
+                    namespace: ItemNamespace::User,
+                    item: SimplifiedItem::MaterializedView {
+                        referenced_names: vec!["u1".to_string()],
+                    },
+                },
+            ],
+            migrated_names: vec!["s1".to_string()],
+            // `u1` is disabled, so its read-from edge to `s1` and its
+            // write-to edge to `u2` are both excluded: migrating `s1` does
+            // not cascade through `u1` to `u2`, even though `u2` otherwise
+            // transitively depends on `s1`.
+            disabled_names: vec!["u1".to_string()],
+            unchanged_system_names: vec![],
+            expected_previous_sink_names: vec![],
+            expected_previous_materialized_view_names: vec![],
+            expected_previous_source_names: vec!["s1".to_string()],
+            expected_all_drop_ops: vec!["s1".to_string()],
+            expected_user_drop_ops: vec![],
+            expected_all_create_ops: vec!["s1".to_string()],
+            expected_user_create_ops: vec![],
+            expected_migrated_system_object_mappings: vec!["s1".to_string()],
+        },
+        BuiltinMigrationTestCase {
+            test_name: "unchanged_dependent_excluded_from_cascade",
+            initial_state: vec![
+                SimplifiedCatalogEntry {
+                    name: "s1".to_string(),
+                    namespace: ItemNamespace::System,
+                    item: SimplifiedItem::Table,
+                },
+                SimplifiedCatalogEntry {
+                    name: "s2".to_string(),
+                    namespace: ItemNamespace::System,
+                    item: SimplifiedItem::Index {
+                        on: "s1".to_string(),
+                    },
+                },
+                SimplifiedCatalogEntry {
+                    name: "u1".to_string(),
+                    namespace: ItemNamespace::User,
+                    item: SimplifiedItem::MaterializedView {
+                        referenced_names: vec!["s2".to_string()],
+                    },
+                },
+            ],
+            // `s1`'s fingerprint changed, so it's in `migrated_names`. `s2`
+            // reads from `s1` but its own effective definition is unchanged
+            // (it's in `unchanged_system_names`), so it keeps its own
+            // `GlobalId` and is excluded from the fingerprint cascade --
+            // but it still gets dropped and recreated at that *same* id so
+            // its `create_sql` can be rewritten to reference `s1`'s new id
+            // (see the assertion on `s2_rebuilder` below). `u1`, which only
+            // reads from `s2`, sees no id change from that and is pruned
+            // from the cascade entirely.
+            migrated_names: vec!["s1".to_string()],
+            disabled_names: vec![],
+            unchanged_system_names: vec!["s2".to_string()],
+            expected_previous_sink_names: vec![],
+            expected_previous_materialized_view_names: vec![],
+            expected_previous_source_names: vec!["s1".to_string()],
+            expected_all_drop_ops: vec!["s2".to_string(), "s1".to_string()],
+            expected_user_drop_ops: vec![],
+            expected_all_create_ops: vec!["s1".to_string(), "s2".to_string()],
+            expected_user_create_ops: vec![],
+            expected_migrated_system_object_mappings: vec!["s1".to_string()],
+        },
     ];
 
     for test_case in test_cases {
@@ -2260,17 +3862,75 @@ async fn test_builtin_migration() {
                 .into_iter()
                 .map(|name| id_mapping[&name])
                 .collect();
+            let disabled_ids: BTreeSet<GlobalId> = test_case
+                .disabled_names
+                .into_iter()
+                .map(|name| id_mapping[&name])
+                .collect();
             let id_fingerprint_map: BTreeMap<GlobalId, String> = id_mapping
                 .iter()
                 .filter(|(_name, id)| id.is_system())
                 // We don't use the new fingerprint in this test, so we can just hard code it
                 .map(|(_name, id)| (*id, "".to_string()))
                 .collect();
+            let unchanged_system_names: BTreeSet<String> =
+                test_case.unchanged_system_names.into_iter().collect();
+            let previous_fingerprints: BTreeMap<GlobalId, String> = id_mapping
+                .iter()
+                .filter(|(_name, id)| id.is_system())
+                .map(|(name, id)| {
+                    let fingerprint = if unchanged_system_names.contains(name) {
+                        "".to_string()
+                    } else {
+                        "stale".to_string()
+                    };
+                    (*id, fingerprint)
+                })
+                .collect();
             let migration_metadata = catalog
-                .generate_builtin_migration_metadata(migrated_ids, id_fingerprint_map)
+                .generate_builtin_migration_metadata(
+                    migrated_ids,
+                    id_fingerprint_map,
+                    &previous_fingerprints,
+                    &disabled_ids,
+                )
                 .await
                 .expect("failed to generate builtin migration metadata");
 
+            if test_case.test_name == "unchanged_dependent_excluded_from_cascade" {
+                let old_s1_id = id_mapping["s1"];
+                let new_s1_id = migration_metadata.ancestor_ids[&old_s1_id];
+                let (_, _, _, _, _, s2_rebuilder) = migration_metadata
+                    .all_create_ops
+                    .iter()
+                    .find(|(_, _, name, _, _, _)| name.item == "s2")
+                    .expect(
+                        "s2 must still be rebuilt (at its own id) so its create_sql can be \
+                         rewritten to point at s1's new id, even though s2's own fingerprint \
+                         didn't change",
+                    );
+                match s2_rebuilder {
+                    CatalogItemRebuilder::Object { id, sql, .. } => {
+                        assert_eq!(
+                            *id, id_mapping["s2"],
+                            "s2 keeps its own GlobalId -- only its embedded references change"
+                        );
+                        assert!(
+                            sql.contains(&new_s1_id.to_string()),
+                            "s2's rewritten create_sql ({sql:?}) must reference s1's new id \
+                             ({new_s1_id})"
+                        );
+                        assert!(
+                            !sql.contains(&format!("{old_s1_id})"))
+                                && !sql.contains(&format!("{old_s1_id} ")),
+                            "s2's rewritten create_sql ({sql:?}) must no longer reference s1's \
+                             old, dropped id ({old_s1_id})"
+                        );
+                    }
+                    other => panic!("expected s2 to rebuild as an Object, got {other:?}"),
+                }
+            }
+
             assert_eq!(
                 convert_id_vec_to_name_vec(migration_metadata.previous_sink_ids, &name_mapping),
                 test_case.expected_previous_sink_names,
@@ -2340,4 +4000,337 @@ async fn test_builtin_migration() {
         })
         .await
     }
-}
\ No newline at end of file
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_effective_privileges_inheritance() {
+    use std::collections::BTreeSet;
+
+    use mz_expr::MirRelationExpr;
+    use mz_ore::now::NOW_ZERO;
+    use mz_repr::adt::mz_acl_item::{AclMode, MzAclItem, PrivilegeMap};
+    use mz_repr::role_id::RoleId;
+    use mz_repr::{GlobalId, RelationType};
+    use mz_sql::catalog::CatalogDatabase;
+    use mz_sql::names::{ItemQualifiers, QualifiedItemName, ResolvedDatabaseSpecifier};
+    use mz_sql::session::user::MZ_SYSTEM_ROLE_ID;
+
+    use crate::catalog::RelationDesc;
+    use crate::catalog::{
+        Catalog, CatalogItem, OptimizedMirRelationExpr, Table, View, DEFAULT_SCHEMA,
+        SYSTEM_CONN_ID,
+    };
+    use crate::session::DEFAULT_DATABASE_NAME;
+
+    // A test grantee distinct from the owner, used only to check whether a
+    // privilege reached it -- it never needs to correspond to a real role
+    // for `effective_privileges` to compute over it.
+    let grantee = RoleId::User(42);
+    let select_grant = MzAclItem {
+        grantee,
+        grantor: MZ_SYSTEM_ROLE_ID,
+        acl_mode: AclMode::SELECT,
+    };
+
+    async fn add_item(
+        catalog: &mut Catalog,
+        name: String,
+        item: CatalogItem,
+        privileges: PrivilegeMap,
+    ) -> GlobalId {
+        let id = catalog
+            .allocate_user_id()
+            .await
+            .expect("cannot fail to allocate user ids");
+        let oid = catalog
+            .allocate_oid()
+            .expect("cannot fail to allocate oids");
+        let database_id = catalog
+            .resolve_database(DEFAULT_DATABASE_NAME)
+            .expect("failed to resolve default database")
+            .id();
+        let database_spec = ResolvedDatabaseSpecifier::Id(database_id);
+        let schema_spec = catalog
+            .resolve_schema_in_database(&database_spec, DEFAULT_SCHEMA, &SYSTEM_CONN_ID)
+            .expect("failed to resolve default schemas")
+            .id
+            .clone();
+        // Bypasses `transact`/`Op::CreateItem` (which always derives a
+        // fresh owner-only `PrivilegeMap`) so the test can seed an explicit
+        // non-owner grant directly, the same way bootstrap populates
+        // builtin objects' privileges.
+        catalog.state.insert_item(
+            id,
+            oid,
+            QualifiedItemName {
+                qualifiers: ItemQualifiers {
+                    database_spec,
+                    schema_spec,
+                },
+                item: name,
+            },
+            item,
+            MZ_SYSTEM_ROLE_ID,
+            privileges,
+        );
+        id
+    }
+
+    fn view_item(on: GlobalId) -> CatalogItem {
+        CatalogItem::View(View {
+            create_sql: "CREATE VIEW v AS SELECT * FROM on".to_string(),
+            optimized_expr: OptimizedMirRelationExpr(MirRelationExpr::Constant {
+                rows: Ok(Vec::new()),
+                typ: RelationType {
+                    column_types: Vec::new(),
+                    keys: Vec::new(),
+                },
+            }),
+            desc: RelationDesc::empty()
+                .with_column("a", mz_repr::ScalarType::Int32.nullable(true))
+                .with_key(vec![0]),
+            conn_id: None,
+            resolved_ids: mz_sql::names::ResolvedIds(BTreeSet::from_iter([on])),
+        })
+    }
+
+    Catalog::with_debug(NOW_ZERO.clone(), |mut catalog| async move {
+        // t1 --reads_from-- v1 --reads_from-- v2, a two-hop chain. Only t1
+        // carries a direct grant to `grantee`; v1 and v2 carry none.
+        let t1 = add_item(
+            &mut catalog,
+            "t1".to_string(),
+            CatalogItem::Table(Table {
+                create_sql: "TODO".to_string(),
+                desc: RelationDesc::empty()
+                    .with_column("a", mz_repr::ScalarType::Int32.nullable(true))
+                    .with_key(vec![0]),
+                defaults: vec![Expr::null(); 1],
+                conn_id: None,
+                resolved_ids: mz_sql::names::ResolvedIds(BTreeSet::new()),
+                custom_logical_compaction_window: None,
+                is_retained_metrics_object: false,
+            }),
+            PrivilegeMap::from_mz_acl_items(vec![select_grant.clone()]),
+        )
+        .await;
+        let v1 = add_item(
+            &mut catalog,
+            "v1".to_string(),
+            view_item(t1),
+            PrivilegeMap::default(),
+        )
+        .await;
+        let v2 = add_item(
+            &mut catalog,
+            "v2".to_string(),
+            view_item(v1),
+            PrivilegeMap::default(),
+        )
+        .await;
+
+        let v2_entry = catalog.get_entry(&v2);
+
+        let independent = catalog
+            .effective_privileges(v2_entry, PrivilegeInheritancePolicy::Independent)
+            .all_values_owned()
+            .collect::<Vec<_>>();
+        assert!(
+            !independent.contains(&select_grant),
+            "Independent must not pull in t1's grant two hops away"
+        );
+
+        let inherited = catalog
+            .effective_privileges(v2_entry, PrivilegeInheritancePolicy::Inherit)
+            .all_values_owned()
+            .collect::<Vec<_>>();
+        assert!(
+            inherited.contains(&select_grant),
+            "Inherit must pull in t1's grant transitively through v1"
+        );
+    })
+    .await
+}
+
+#[mz_ore::test(tokio::test)]
+async fn test_run_catalog_migrate_cli() {
+    let journal = BuiltinMigrationJournal {
+        status: BuiltinMigrationStatus::Planned,
+        all_drop_ops: vec![GlobalId::System(1)],
+        all_create_ops: vec![GlobalId::System(2)],
+        ancestor_ids: BTreeMap::from([(GlobalId::System(1), GlobalId::System(2))]),
+    };
+
+    // With no journal at all, there's nothing pending.
+    let mut empty_backend = InMemoryCatalogStorage::default();
+    let args = CatalogMigrateArgs {
+        backend_path: CatalogMigrateArgs::DEFAULT_BACKEND_PATH.to_string(),
+        dry_run: false,
+    };
+    let relation = run_catalog_migrate_cli(&args, &mut empty_backend)
+        .await
+        .expect("no pending journal is not an error");
+    assert!(relation.is_empty());
+
+    // A dry run prints and returns the pending mapping, but leaves the
+    // journal exactly as `Planned`.
+    let mut backend = InMemoryCatalogStorage::default();
+    backend
+        .set_builtin_migration_journal(journal.clone())
+        .await
+        .expect("set_builtin_migration_journal does not fail");
+    let dry_run_args = CatalogMigrateArgs {
+        backend_path: CatalogMigrateArgs::DEFAULT_BACKEND_PATH.to_string(),
+        dry_run: true,
+    };
+    let relation = run_catalog_migrate_cli(&dry_run_args, &mut backend)
+        .await
+        .expect("dry run does not fail");
+    assert_eq!(relation.len(), 1);
+    assert_eq!(
+        backend
+            .get_builtin_migration_journal()
+            .await
+            .expect("read does not fail")
+            .expect("journal is still present")
+            .status,
+        BuiltinMigrationStatus::Planned,
+        "a dry run must not advance the journal's status"
+    );
+
+    // A real run drives the journal from `Planned` through to `Committed`.
+    let args = CatalogMigrateArgs {
+        backend_path: CatalogMigrateArgs::DEFAULT_BACKEND_PATH.to_string(),
+        dry_run: false,
+    };
+    let relation = run_catalog_migrate_cli(&args, &mut backend)
+        .await
+        .expect("a real run does not fail");
+    assert_eq!(relation.len(), 1);
+    assert_eq!(
+        backend
+            .get_builtin_migration_journal()
+            .await
+            .expect("read does not fail")
+            .expect("journal is still present")
+            .status,
+        BuiltinMigrationStatus::Committed,
+    );
+}
+
+#[mz_ore::test(tokio::test)]
+#[cfg_attr(miri, ignore)] //  unsupported operation: can't call foreign function `TLS_client_method` on OS `linux`
+async fn test_information_schema_reflects_catalog_entries() {
+    use std::collections::BTreeSet;
+
+    use mz_expr::MirRelationExpr;
+    use mz_ore::now::NOW_ZERO;
+    use mz_repr::adt::mz_acl_item::PrivilegeMap;
+    use mz_repr::{GlobalId, RelationType};
+    use mz_sql::names::{ItemQualifiers, QualifiedItemName, ResolvedDatabaseSpecifier};
+    use mz_sql::session::user::MZ_SYSTEM_ROLE_ID;
+
+    use crate::catalog::RelationDesc;
+    use crate::catalog::{
+        Catalog, CatalogItem, OptimizedMirRelationExpr, Table, View, DEFAULT_SCHEMA,
+        SYSTEM_CONN_ID,
+    };
+    use crate::session::DEFAULT_DATABASE_NAME;
+
+    async fn add_item(catalog: &mut Catalog, name: String, item: CatalogItem) -> GlobalId {
+        let id = catalog
+            .allocate_user_id()
+            .await
+            .expect("cannot fail to allocate user ids");
+        let oid = catalog
+            .allocate_oid()
+            .expect("cannot fail to allocate oids");
+        let database_id = catalog
+            .resolve_database(DEFAULT_DATABASE_NAME)
+            .expect("failed to resolve default database")
+            .id();
+        let database_spec = ResolvedDatabaseSpecifier::Id(database_id);
+        let schema_spec = catalog
+            .resolve_schema_in_database(&database_spec, DEFAULT_SCHEMA, &SYSTEM_CONN_ID)
+            .expect("failed to resolve default schemas")
+            .id
+            .clone();
+        catalog.state.insert_item(
+            id,
+            oid,
+            QualifiedItemName {
+                qualifiers: ItemQualifiers {
+                    database_spec,
+                    schema_spec,
+                },
+                item: name,
+            },
+            item,
+            MZ_SYSTEM_ROLE_ID,
+            PrivilegeMap::default(),
+        );
+        id
+    }
+
+    Catalog::with_debug(NOW_ZERO.clone(), |mut catalog| async move {
+        let t1 = add_item(
+            &mut catalog,
+            "t1".to_string(),
+            CatalogItem::Table(Table {
+                create_sql: "TODO".to_string(),
+                desc: RelationDesc::empty()
+                    .with_column("a", mz_repr::ScalarType::Int32.nullable(false))
+                    .with_column("b", mz_repr::ScalarType::String.nullable(true))
+                    .with_key(vec![0]),
+                defaults: vec![Expr::null(); 2],
+                conn_id: None,
+                resolved_ids: mz_sql::names::ResolvedIds(BTreeSet::new()),
+                custom_logical_compaction_window: None,
+                is_retained_metrics_object: false,
+            }),
+        )
+        .await;
+        add_item(
+            &mut catalog,
+            "v1".to_string(),
+            CatalogItem::View(View {
+                create_sql: "CREATE VIEW v1 AS SELECT * FROM t1".to_string(),
+                optimized_expr: OptimizedMirRelationExpr(MirRelationExpr::Constant {
+                    rows: Ok(Vec::new()),
+                    typ: RelationType {
+                        column_types: Vec::new(),
+                        keys: Vec::new(),
+                    },
+                }),
+                desc: RelationDesc::empty()
+                    .with_column("a", mz_repr::ScalarType::Int32.nullable(false)),
+                conn_id: None,
+                resolved_ids: mz_sql::names::ResolvedIds(BTreeSet::from_iter([t1])),
+            }),
+        )
+        .await;
+
+        let columns = catalog.information_schema_columns();
+        let t1_columns: Vec<_> = columns
+            .iter()
+            .filter(|column| column.table_name == "t1")
+            .collect();
+        assert_eq!(t1_columns.len(), 2, "t1 has two columns");
+        assert_eq!(t1_columns[0].column_name, "a");
+        assert_eq!(t1_columns[0].ordinal_position, 1);
+        assert!(!t1_columns[0].is_nullable);
+        assert_eq!(t1_columns[1].column_name, "b");
+        assert_eq!(t1_columns[1].ordinal_position, 2);
+        assert!(t1_columns[1].is_nullable);
+
+        let tables = catalog.information_schema_tables();
+        let table_types: std::collections::BTreeMap<_, _> = tables
+            .iter()
+            .map(|table| (table.table_name.as_str(), table.table_type))
+            .collect();
+        assert_eq!(table_types.get("t1"), Some(&"BASE TABLE"));
+        assert_eq!(table_types.get("v1"), Some(&"VIEW"));
+    })
+    .await
+}
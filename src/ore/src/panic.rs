@@ -0,0 +1,263 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Panic handling utilities.
+
+use std::any::Any;
+use std::backtrace::Backtrace;
+use std::cell::RefCell;
+use std::panic::{self, PanicHookInfo};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, OnceLock};
+
+/// The id of a hook registered with [`add_hook`], returned so it can later
+/// be removed with [`remove_hook`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct HookId(usize);
+
+type Hook = Box<dyn Fn(&PanicHookInfo) + Send + Sync + 'static>;
+
+struct HookRegistry {
+    /// The previously installed hook, captured at [`install_enhanced_handler`]
+    /// time. Always invoked last, so we chain onto the default/std behavior
+    /// rather than replacing it.
+    previous: Box<dyn Fn(&PanicHookInfo) + Send + Sync + 'static>,
+    hooks: Vec<(HookId, Hook)>,
+}
+
+static REGISTRY: Mutex<Option<HookRegistry>> = Mutex::new(None);
+static NEXT_HOOK_ID: AtomicUsize = AtomicUsize::new(0);
+static INSTALLED: OnceLock<()> = OnceLock::new();
+
+thread_local! {
+    /// The location and backtrace of the panic currently unwinding on this
+    /// thread, stashed by the enhanced hook (installed via
+    /// [`install_enhanced_handler`]) before the unwind begins. The panic
+    /// payload alone carries no location info, so it has to be
+    /// side-channeled through here and picked back up by
+    /// [`catch_unwind_info`] once the unwind has been caught.
+    static LAST_PANIC_CONTEXT: RefCell<Option<(Option<PanicLocation>, Backtrace)>> =
+        const { RefCell::new(None) };
+}
+
+/// The process-wide policy for what happens after a panic's hooks have run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PanicStrategy {
+    /// The default: continue unwinding after hooks run, giving
+    /// `catch_unwind`-family functions a chance to observe the panic.
+    Unwind,
+    /// Run all registered hooks (and the chained previous hook) as usual,
+    /// then call [`std::process::abort`] instead of returning to the
+    /// unwind machinery. Guarantees a panic on any thread brings the whole
+    /// process down deterministically with a core dump, rather than
+    /// silently poisoning a mutex or leaving a task half-dead.
+    AbortAfterHooks,
+}
+
+static STRATEGY: Mutex<PanicStrategy> = Mutex::new(PanicStrategy::Unwind);
+
+/// Sets the process-wide [`PanicStrategy`].
+pub fn set_panic_strategy(strategy: PanicStrategy) {
+    *STRATEGY.lock().expect("lock poisoned") = strategy;
+}
+
+/// Returns the current process-wide [`PanicStrategy`].
+///
+/// In [`PanicStrategy::AbortAfterHooks`] mode, [`catch_unwind_str`] and its
+/// siblings can never observe an `Err`, since the process aborts before
+/// control returns to them; callers and test code should check this to
+/// branch accordingly, e.g. to skip a test that deliberately triggers a
+/// panic to assert on its message.
+pub fn current_strategy() -> PanicStrategy {
+    *STRATEGY.lock().expect("lock poisoned")
+}
+
+/// Convenience wrapper around [`set_panic_strategy`] for the common case of
+/// toggling [`PanicStrategy::AbortAfterHooks`] on or off.
+pub fn set_abort_on_panic(enabled: bool) {
+    set_panic_strategy(if enabled {
+        PanicStrategy::AbortAfterHooks
+    } else {
+        PanicStrategy::Unwind
+    });
+}
+
+/// The file, line, and column at which a panic occurred.
+#[derive(Debug, Clone)]
+pub struct PanicLocation {
+    /// The source file of the panic site.
+    pub file: String,
+    /// The line number of the panic site.
+    pub line: u32,
+    /// The column number of the panic site.
+    pub column: u32,
+}
+
+/// Installs an enhanced panic hook that runs all hooks registered via
+/// [`add_hook`], in registration order, and then chains onto whatever hook
+/// was previously installed (e.g. the standard library's default hook).
+///
+/// Idempotent: calling this more than once only installs the hook once.
+pub fn install_enhanced_handler() {
+    INSTALLED.get_or_init(|| {
+        let previous = panic::take_hook();
+        *REGISTRY.lock().expect("lock poisoned") = Some(HookRegistry {
+            previous,
+            hooks: Vec::new(),
+        });
+        panic::set_hook(Box::new(|info| {
+            let location = info.location().map(|loc| PanicLocation {
+                file: loc.file().to_string(),
+                line: loc.line(),
+                column: loc.column(),
+            });
+            LAST_PANIC_CONTEXT.with(|ctx| {
+                *ctx.borrow_mut() = Some((location, Backtrace::capture()));
+            });
+
+            let registry = REGISTRY.lock().expect("lock poisoned");
+            let Some(registry) = registry.as_ref() else {
+                return;
+            };
+            for (_, hook) in &registry.hooks {
+                hook(info);
+            }
+            (registry.previous)(info);
+
+            if current_strategy() == PanicStrategy::AbortAfterHooks {
+                std::process::abort();
+            }
+        }));
+    });
+}
+
+/// Registers `hook` to be called, with the [`PanicHookInfo`], whenever a
+/// panic occurs, in addition to (and before) whatever hook was installed
+/// prior to [`install_enhanced_handler`]. Returns a [`HookId`] that can be
+/// passed to [`remove_hook`] to unregister it.
+///
+/// Panics if [`install_enhanced_handler`] has not been called yet.
+pub fn add_hook<F>(hook: F) -> HookId
+where
+    F: Fn(&PanicHookInfo) + Send + Sync + 'static,
+{
+    let id = HookId(NEXT_HOOK_ID.fetch_add(1, Ordering::Relaxed));
+    let mut registry = REGISTRY.lock().expect("lock poisoned");
+    let registry = registry
+        .as_mut()
+        .expect("install_enhanced_handler must be called before add_hook");
+    registry.hooks.push((id, Box::new(hook)));
+    id
+}
+
+/// Unregisters a hook previously registered with [`add_hook`]. A no-op if
+/// `id` has already been removed.
+pub fn remove_hook(id: HookId) {
+    let mut registry = REGISTRY.lock().expect("lock poisoned");
+    if let Some(registry) = registry.as_mut() {
+        registry.hooks.retain(|(hook_id, _)| *hook_id != id);
+    }
+}
+
+/// Like [`std::panic::catch_unwind`], but converts the panic payload to a
+/// `String` on failure.
+pub fn catch_unwind_str<F, R>(f: F) -> Result<R, String>
+where
+    F: FnOnce() -> R + panic::UnwindSafe,
+{
+    panic::catch_unwind(f).map_err(|payload| downcast_panic_payload(&payload))
+}
+
+/// A panic captured by [`catch_unwind_info`], with more context than the
+/// bare message [`catch_unwind_str`] returns.
+#[derive(Debug)]
+pub struct CaughtPanic {
+    /// The downcast panic message, as in [`catch_unwind_str`].
+    pub message: String,
+    /// The location of the panic site, if available. Only populated when
+    /// [`install_enhanced_handler`] has been called; otherwise `None`.
+    pub location: Option<PanicLocation>,
+    /// A backtrace captured at the panic site (at the catch site if
+    /// [`install_enhanced_handler`] has not been called). Whether this contains
+    /// resolved frames depends on the `RUST_BACKTRACE`/`RUST_LIB_BACKTRACE`
+    /// environment variables, per [`std::backtrace::Backtrace`].
+    pub backtrace: Backtrace,
+    /// The name of the thread the panic occurred on, if it had one.
+    pub thread_name: Option<String>,
+}
+
+/// Like [`catch_unwind_str`], but returns a [`CaughtPanic`] carrying the
+/// panic's location and backtrace in addition to its message.
+///
+/// Requires [`install_enhanced_handler`] to have been called in order to
+/// populate `CaughtPanic::location` and `CaughtPanic::backtrace`; without
+/// it, `location` is `None` and `backtrace` is captured here, at the catch
+/// site rather than the panic site, since the payload alone carries no such
+/// context.
+pub fn catch_unwind_info<F, R>(f: F) -> Result<R, CaughtPanic>
+where
+    F: FnOnce() -> R + panic::UnwindSafe,
+{
+    let thread_name = std::thread::current().name().map(|s| s.to_string());
+    panic::catch_unwind(f).map_err(|payload| {
+        let message = downcast_panic_payload(&payload);
+        let (location, backtrace) = LAST_PANIC_CONTEXT
+            .with(|ctx| ctx.borrow_mut().take())
+            .unwrap_or((None, Backtrace::capture()));
+        CaughtPanic {
+            message,
+            location,
+            backtrace,
+            thread_name,
+        }
+    })
+}
+
+/// Like [`std::panic::catch_unwind`], but returns the opaque panic payload
+/// rather than stringifying it, so it can be passed to [`repropagate`]
+/// losslessly at a later point (e.g. once it has been ferried across an
+/// FFI or async-runtime boundary that can't unwind through it directly).
+pub fn catch_unwind_raw<F, R>(f: F) -> Result<R, Box<dyn Any + Send>>
+where
+    F: FnOnce() -> R + panic::UnwindSafe,
+{
+    panic::catch_unwind(f)
+}
+
+/// Resumes unwinding with a payload previously captured by
+/// [`catch_unwind_raw`] (or [`std::panic::catch_unwind`]).
+/// [`catch_unwind_str`] and [`catch_unwind_info`] return a converted message
+/// rather than the payload, so their results can't be passed here.
+///
+/// Unlike a fresh panic, this does *not* run the registered hooks a second
+/// time: [`std::panic::resume_unwind`] is implemented without invoking the
+/// panic hook at all (by design, since the unwind is already in progress),
+/// so whatever hooks ran when the panic was first caught are the only ones
+/// that will run for it.
+pub fn repropagate(payload: Box<dyn Any + Send>) -> ! {
+    panic::resume_unwind(payload)
+}
+
+/// Converts a panic payload, as captured by [`std::panic::catch_unwind`] or
+/// a [`PanicHookInfo`], to a displayable `String`.
+fn downcast_panic_payload(payload: &(dyn Any + Send + 'static)) -> String {
+    if let Some(s) = payload.downcast_ref::<&str>() {
+        s.to_string()
+    } else if let Some(s) = payload.downcast_ref::<String>() {
+        s.clone()
+    } else {
+        "unknown panic payload".to_string()
+    }
+}
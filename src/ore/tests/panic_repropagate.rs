@@ -0,0 +1,115 @@
+// Copyright Materialize, Inc. and contributors. All rights reserved.
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License in the LICENSE file at the
+// root of this repository, or online at
+//
+//     http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use std::panic;
+use std::process::Command;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use mz_ore::panic::{
+    catch_unwind_info, catch_unwind_raw, install_enhanced_handler, repropagate, set_abort_on_panic,
+};
+use scopeguard::defer;
+
+// IMPORTANT!!! Do not add any additional tests to this file. Like
+// `tests/panic.rs`, this sets and removes panic hooks (and, for
+// `abort_after_hooks`, spawns a child process that deliberately aborts) and
+// can interfere with any concurrently running test. Therefore, it needs to
+// be run in isolation.
+
+const ABORT_CHILD_ENV: &str = "MZ_ORE_PANIC_TEST_ABORT_CHILD";
+
+#[test] // allow(test-attribute)
+fn catch_unwind_info_captures_location() {
+    let old_hook = panic::take_hook();
+    defer! {
+        panic::set_hook(old_hook);
+    }
+
+    install_enhanced_handler();
+
+    let caught = catch_unwind_info(|| {
+        panic!("boom");
+    })
+    .unwrap_err();
+
+    assert_eq!(caught.message, "boom");
+    let location = caught.location.expect("enhanced handler populates location");
+    assert!(location.file.ends_with("panic_repropagate.rs"));
+}
+
+#[test] // allow(test-attribute)
+fn repropagate_preserves_payload_and_runs_hooks_once() {
+    let old_hook = panic::take_hook();
+    defer! {
+        panic::set_hook(old_hook);
+    }
+
+    install_enhanced_handler();
+
+    static HOOK_RUNS: AtomicUsize = AtomicUsize::new(0);
+    mz_ore::panic::add_hook(|_| {
+        HOOK_RUNS.fetch_add(1, Ordering::SeqCst);
+    });
+
+    let payload = catch_unwind_raw(|| {
+        panic!("will be repropagated");
+    })
+    .unwrap_err();
+
+    // The enhanced hook runs once for the original panic.
+    assert_eq!(HOOK_RUNS.load(Ordering::SeqCst), 1);
+
+    let result = catch_unwind_raw(|| repropagate(payload)).unwrap_err();
+    let message = *result.downcast::<&str>().expect("payload is a &str");
+    assert_eq!(message, "will be repropagated");
+
+    // `resume_unwind` never invokes the panic hook, so the count is
+    // unchanged by the repropagated unwind.
+    assert_eq!(HOOK_RUNS.load(Ordering::SeqCst), 1);
+}
+
+/// `PanicStrategy::AbortAfterHooks` calls `std::process::abort` instead of
+/// returning control to the unwind machinery, so it can only be observed
+/// from outside the panicking process: this test re-execs itself into a
+/// child that panics under the strategy, and asserts the child never
+/// returns normally.
+#[test] // allow(test-attribute)
+fn abort_after_hooks_aborts_the_process() {
+    if std::env::var_os(ABORT_CHILD_ENV).is_some() {
+        install_enhanced_handler();
+        set_abort_on_panic(true);
+        panic!("this process should abort, not unwind");
+    }
+
+    let exe = std::env::current_exe().expect("current_exe");
+    let status = Command::new(exe)
+        .arg("--exact")
+        .arg("abort_after_hooks_aborts_the_process")
+        .arg("--nocapture")
+        .env(ABORT_CHILD_ENV, "1")
+        .status()
+        .expect("failed to spawn child process");
+
+    assert!(
+        !status.success(),
+        "child process should not exit successfully"
+    );
+    // A normal (unwind-based) test failure exits with code 101; aborting
+    // instead terminates via a signal (no exit code on Unix) or a distinct
+    // abort exit code on Windows, so this distinguishes "the process
+    // unwound and the test harness reported a failure" from "the process
+    // actually aborted".
+    assert_ne!(status.code(), Some(101));
+}
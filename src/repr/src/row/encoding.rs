@@ -11,6 +11,9 @@
 //!
 //! See row.proto for details.
 
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
 use bytes::BufMut;
 use chrono::Timelike;
 use dec::Decimal;
@@ -37,6 +40,73 @@ use crate::row::{
 };
 use crate::{ColumnType, Datum, RelationDesc, Row, RowPacker, ScalarType};
 
+/// The on-disk marker for [Row]'s original versioned storage encoding (V2):
+/// a single byte, written as the first byte of the buffer, in front of a
+/// bare `ProtoRow`.
+///
+/// A bare `ProtoRow` always starts with the protobuf tag byte for field 1
+/// (`datums`), which is `(1 << 3) | 2 = 0x0A`, so its high bit is always
+/// clear. V2 reused that high bit as a discriminant: any byte with the high
+/// bit set can't be the start of a legacy (V1, unversioned) `ProtoRow`, so
+/// it's safe to reserve those values as version markers without risking a
+/// collision with data written before this scheme existed. V3 (see
+/// [ROW_ENCODING_MAGIC]) keeps reusing that same high bit, so all three
+/// shapes remain unambiguous.
+const ROW_ENCODING_VERSION_V2: u8 = 128;
+
+/// The on-disk marker for [Row]'s V3+ storage encoding: this fixed byte,
+/// followed by the format version as a protobuf-style varint, followed by
+/// that version's payload.
+///
+/// V2 baked its version directly into the marker byte, capping it at 127
+/// possible versions (128..=255) with no room to describe the payload
+/// further. Spelling the version out as its own varint removes that cap, so
+/// the format can keep growing new versions indefinitely; see
+/// [CURRENT_ROW_ENCODING_VERSION] and [decode_proto_row_for_version] for the
+/// registry of what each version's payload looks like.
+const ROW_ENCODING_MAGIC: u8 = 0xFF;
+
+/// The row encoding version written by [Row::encode].
+///
+/// Bump this, and add a matching arm to [decode_proto_row_for_version],
+/// whenever a change to `ProtoRow` (or how we encode/decode it) would change
+/// these bytes for existing data. The `golden` tests below exist to catch
+/// exactly that: an accidental bytes change without a version bump.
+const CURRENT_ROW_ENCODING_VERSION: u64 = 3;
+
+/// Splits the version marker off the front of an encoded [Row] buffer,
+/// returning the resolved format version and the remaining payload bytes.
+///
+/// Handles every shape this crate has ever produced: the unversioned legacy
+/// `ProtoRow` (version 1, no marker at all), the single-byte V2 marker
+/// (version 2), and the [ROW_ENCODING_MAGIC] + varint scheme used from V3 on.
+fn split_row_encoding_version(buf: &[u8]) -> Result<(u64, &[u8]), String> {
+    match buf.split_first() {
+        Some((&ROW_ENCODING_MAGIC, mut rest)) => {
+            let version = prost::encoding::decode_varint(&mut rest)
+                .map_err(|err| format!("invalid row encoding version varint: {err}"))?;
+            Ok((version, rest))
+        }
+        Some((version, rest)) if *version >= ROW_ENCODING_VERSION_V2 => Ok((2, rest)),
+        _ => Ok((1, buf)),
+    }
+}
+
+/// Decodes a `ProtoRow` payload written at the given format `version` (see
+/// [split_row_encoding_version]).
+///
+/// This is the "registered decoder" the version header exists to dispatch
+/// to: every historical version gets its own arm, so stored bytes stay
+/// readable even after `ProtoRow`'s schema moves on. V1 and V2 both wrote a
+/// bare `ProtoRow` with no extra framing, so they share an arm; a version
+/// whose payload framing actually changes would get its own.
+fn decode_proto_row_for_version(version: u64, buf: &[u8]) -> Result<ProtoRow, String> {
+    match version {
+        1 | 2 | 3 => ProtoRow::decode(buf).map_err(|err| err.to_string()),
+        _ => Err(format!("unknown row encoding version: {version}")),
+    }
+}
+
 impl Codec for Row {
     type Schema = RelationDesc;
 
@@ -46,13 +116,16 @@ impl Codec for Row {
 
     /// Encodes a row into the permanent storage format.
     ///
-    /// This perfectly round-trips through [Row::decode]. It's guaranteed to be
-    /// readable by future versions of Materialize through v(TODO: Figure out
-    /// our policy).
+    /// This perfectly round-trips through [Row::decode]. The encoding is
+    /// versioned (see [CURRENT_ROW_ENCODING_VERSION]), so the format can
+    /// evolve in the future without ambiguity, while everything written to
+    /// date remains readable.
     fn encode<B>(&self, buf: &mut B)
     where
         B: BufMut,
     {
+        buf.put_u8(ROW_ENCODING_MAGIC);
+        prost::encoding::encode_varint(CURRENT_ROW_ENCODING_VERSION, buf);
         self.into_proto()
             .encode(buf)
             .expect("no required fields means no initialization errors");
@@ -60,15 +133,36 @@ impl Codec for Row {
 
     /// Decodes a row from the permanent storage format.
     ///
-    /// This perfectly round-trips through [Row::encode]. It can read rows
-    /// encoded by historical versions of Materialize back to v(TODO: Figure out
-    /// our policy).
+    /// This perfectly round-trips through [Row::encode]. It can also read
+    /// rows written by any encoding this crate has ever produced -- see
+    /// [split_row_encoding_version] and [decode_proto_row_for_version].
     fn decode(buf: &[u8]) -> Result<Row, String> {
-        let proto_row = ProtoRow::decode(buf).map_err(|err| err.to_string())?;
+        let (version, payload) = split_row_encoding_version(buf)?;
+        let proto_row = decode_proto_row_for_version(version, payload)?;
         Row::try_from(&proto_row)
     }
 }
 
+impl Row {
+    /// An infallible fast path for decoding a [Row] out of bytes that are
+    /// trusted to have been written by our own [Row::encode] (e.g. a bulk
+    /// scan over data we just read back out of persist).
+    ///
+    /// Unlike [Codec::decode], this never returns an error: the protobuf
+    /// message is still parsed (and will panic on outright corruption, the
+    /// same as any other internal invariant violation), but the redundant
+    /// per-datum range validation that protects against untrusted external
+    /// bytes is downgraded to `debug_assert!`s. Do not use this for bytes
+    /// that didn't come from our own encoder.
+    pub fn decode_trusted(buf: &[u8]) -> Row {
+        let (version, payload) = split_row_encoding_version(buf)
+            .expect("trusted row data should have a valid version header");
+        let proto_row = decode_proto_row_for_version(version, payload)
+            .expect("trusted row data should be a valid ProtoRow");
+        Row::from_proto_trusted(&proto_row)
+    }
+}
+
 impl ColumnType {
     /// Returns the [DatumToPersist] implementation for this ColumnType.
     ///
@@ -109,21 +203,23 @@ impl ColumnType {
             (true, String | Char { .. } | VarChar { .. }) => {
                 f.call::<Option<std::string::String>>()
             }
+            (false, Numeric { .. }) => f.call::<NumericToPersist>(),
+            (true, Numeric { .. }) => f.call::<Option<NumericToPersist>>(),
+            (false, Time) => f.call::<TimeToPersist>(),
+            (true, Time) => f.call::<Option<TimeToPersist>>(),
+            (false, Timestamp) => f.call::<TimestampToPersist>(),
+            (true, Timestamp) => f.call::<Option<TimestampToPersist>>(),
+            (false, TimestampTz) => f.call::<TimestampTzToPersist>(),
+            (true, TimestampTz) => f.call::<Option<TimestampTzToPersist>>(),
+            (false, Interval) => f.call::<IntervalToPersist>(),
+            (true, Interval) => f.call::<Option<IntervalToPersist>>(),
+            (false, Uuid) => f.call::<UuidToPersist>(),
+            (true, Uuid) => f.call::<Option<UuidToPersist>>(),
+            (false, MzTimestamp) => f.call::<MzTimestampToPersist>(),
+            (true, MzTimestamp) => f.call::<Option<MzTimestampToPersist>>(),
             (
                 _,
-                Numeric { .. }
-                | Time
-                | Timestamp
-                | TimestampTz
-                | Interval
-                | Jsonb
-                | Uuid
-                | Array(..)
-                | List { .. }
-                | Record { .. }
-                | Map { .. }
-                | Int2Vector
-                | MzTimestamp
+                Jsonb | Array(..) | List { .. } | Record { .. } | Map { .. } | Int2Vector
                 | Range { .. },
             ) => f.call::<TodoDatumToPersist>(),
         }
@@ -142,7 +238,6 @@ impl ColumnType {
 /// of ScalarTypes map to the same set of `Datum`s (e.g. `String` and
 /// `VarChar`).
 ///
-/// TODO: Specify stats fn so we can override it.
 pub trait DatumToPersist {
     /// The persist columnar type we're mapping to/from.
     type Data: Data;
@@ -155,6 +250,85 @@ pub trait DatumToPersist {
     /// Decodes the data in the persist column at the specific offset into a
     /// Datum. This Datum is returned by pushing it in to the given RowPacker.
     fn decode(col: &<Self::Data as Data>::Col, idx: usize, row: &mut RowPacker);
+
+    /// Contributes an encoded value toward this column's min/max statistics
+    /// (see [ColStats]), used for predicate pushdown: a whole persisted part
+    /// can be skipped without reading its data if these bounds can't satisfy
+    /// a filter.
+    ///
+    /// Returns `None` to opt the column out of bounds tracking entirely,
+    /// which the opaque [TodoDatumToPersist] fallback does, since an
+    /// arbitrary nested `Datum` isn't a bound worth comparing against. Every
+    /// other mapping is over an (at least partially) ordered scalar type, so
+    /// the default just hands back the `Datum` itself and relies on `Datum`'s
+    /// own total order.
+    fn stats_key(datum: Datum) -> Option<Datum> {
+        Some(datum)
+    }
+}
+
+/// Lazily-accumulated per-column statistics: null count plus an inclusive
+/// `[lower, upper]` bound over every non-null, [DatumToPersist::stats_key]-eligible
+/// value seen so far. Stored alongside a written persist part, analogous to a
+/// lazily-indexed metadata table, so that filters can skip parts whose bounds
+/// can't match without reading the column's data.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ColStats {
+    null_count: usize,
+    // An owned single-datum `Row` for each bound, so we're not stuck holding
+    // a borrow of whatever `Row` was most recently encoded.
+    bounds: Option<(Row, Row)>,
+}
+
+impl ColStats {
+    /// Folds one encoded datum into the running statistics. `None` means the
+    /// column's [DatumToPersist] opted out of stats (see
+    /// [DatumToPersist::stats_key]), so nothing is recorded.
+    fn observe(&mut self, datum: Option<Datum>) {
+        let Some(datum) = datum else { return };
+        if datum.is_null() {
+            self.null_count += 1;
+            return;
+        }
+        let as_row = Row::pack([datum]);
+        self.bounds = Some(match self.bounds.take() {
+            None => (as_row.clone(), as_row),
+            Some((lower, upper)) => {
+                let lower = if datum < lower.iter().next().expect("single datum row") {
+                    as_row.clone()
+                } else {
+                    lower
+                };
+                let upper = if datum > upper.iter().next().expect("single datum row") {
+                    as_row
+                } else {
+                    upper
+                };
+                (lower, upper)
+            }
+        });
+    }
+
+    /// The number of `Datum::Null`s observed.
+    pub fn null_count(&self) -> usize {
+        self.null_count
+    }
+
+    /// The smallest non-null value observed, if any were and the column
+    /// tracks stats at all.
+    pub fn lower(&self) -> Option<Datum> {
+        self.bounds.as_ref().map(|(lower, _)| {
+            lower.iter().next().expect("single datum row")
+        })
+    }
+
+    /// The largest non-null value observed, if any were and the column
+    /// tracks stats at all.
+    pub fn upper(&self) -> Option<Datum> {
+        self.bounds.as_ref().map(|(_, upper)| {
+            upper.iter().next().expect("single datum row")
+        })
+    }
 }
 
 /// `FnOnce<T: DatumToPersist>() -> R`
@@ -225,6 +399,493 @@ impl DatumToPersist for TodoDatumToPersist {
         row.try_push_proto(&proto)
             .expect("ProtoDatum should be valid Datum");
     }
+    fn stats_key(_datum: Datum) -> Option<Datum> {
+        // An arbitrary nested Datum isn't a meaningful bound to compare
+        // against, so report "no stats" rather than a misleading one.
+        None
+    }
+}
+
+/// Native columnar mappings for the fixed-width, non-primitive `ScalarType`s.
+///
+/// These replace what used to be [TodoDatumToPersist] (an opaque `ProtoDatum`
+/// blob per datum) with a structured encoding that persist can read
+/// column-by-column, without paying for a protobuf envelope on every value.
+/// Only the truly variable, nested types (`Array`, `List`, `Record`, `Map`,
+/// `Range`, `Jsonb`) and `Int2Vector` still fall back to [TodoDatumToPersist].
+#[derive(Debug)]
+pub struct UuidToPersist;
+
+impl DatumToPersist for UuidToPersist {
+    type Data = Vec<u8>;
+    fn encode(col: &mut <Self::Data as Data>::Mut, datum: Datum) {
+        ColumnPush::<Self::Data>::push(col, datum.unwrap_uuid().as_bytes());
+    }
+    fn decode(col: &<Self::Data as Data>::Col, idx: usize, row: &mut RowPacker) {
+        let buf = ColumnGet::<Self::Data>::get(col, idx);
+        let uuid = Uuid::from_slice(buf).expect("column should contain a valid uuid");
+        row.push(Datum::Uuid(uuid));
+    }
+}
+
+impl DatumToPersist for Option<UuidToPersist> {
+    type Data = Option<Vec<u8>>;
+    fn encode(col: &mut <Self::Data as Data>::Mut, datum: Datum) {
+        if datum.is_null() {
+            ColumnPush::<Self::Data>::push(col, None);
+        } else {
+            ColumnPush::<Self::Data>::push(col, Some(datum.unwrap_uuid().as_bytes().to_vec()));
+        }
+    }
+    fn decode(col: &<Self::Data as Data>::Col, idx: usize, row: &mut RowPacker) {
+        match ColumnGet::<Self::Data>::get(col, idx) {
+            None => row.push(Datum::Null),
+            Some(buf) => {
+                let uuid = Uuid::from_slice(buf).expect("column should contain a valid uuid");
+                row.push(Datum::Uuid(uuid));
+            }
+        }
+    }
+}
+
+/// Nanoseconds since midnight.
+#[derive(Debug)]
+pub struct TimeToPersist;
+
+impl TimeToPersist {
+    fn to_nanos(t: chrono::NaiveTime) -> i64 {
+        i64::from(t.num_seconds_from_midnight()) * 1_000_000_000 + i64::from(t.nanosecond())
+    }
+
+    fn from_nanos(nanos: i64) -> chrono::NaiveTime {
+        let secs = u32::cast_from(nanos / 1_000_000_000);
+        let subsec_nanos = u32::cast_from(nanos % 1_000_000_000);
+        chrono::NaiveTime::from_num_seconds_from_midnight_opt(secs, subsec_nanos)
+            .expect("column should contain a valid time-of-day")
+    }
+}
+
+impl DatumToPersist for TimeToPersist {
+    type Data = i64;
+    fn encode(col: &mut <Self::Data as Data>::Mut, datum: Datum) {
+        ColumnPush::<Self::Data>::push(col, Self::to_nanos(datum.unwrap_time()));
+    }
+    fn decode(col: &<Self::Data as Data>::Col, idx: usize, row: &mut RowPacker) {
+        let nanos = ColumnGet::<Self::Data>::get(col, idx);
+        row.push(Datum::Time(Self::from_nanos(nanos)));
+    }
+}
+
+impl DatumToPersist for Option<TimeToPersist> {
+    type Data = Option<i64>;
+    fn encode(col: &mut <Self::Data as Data>::Mut, datum: Datum) {
+        if datum.is_null() {
+            ColumnPush::<Self::Data>::push(col, None);
+        } else {
+            ColumnPush::<Self::Data>::push(col, Some(TimeToPersist::to_nanos(datum.unwrap_time())));
+        }
+    }
+    fn decode(col: &<Self::Data as Data>::Col, idx: usize, row: &mut RowPacker) {
+        match ColumnGet::<Self::Data>::get(col, idx) {
+            None => row.push(Datum::Null),
+            Some(nanos) => row.push(Datum::Time(TimeToPersist::from_nanos(nanos))),
+        }
+    }
+}
+
+/// Computes the fractional-second component of `dt` at the given
+/// `precision` (0-9), per the scaling table described for
+/// `ProtoTimestamp`/`ProtoTimestampTz`'s optional `precision` field: `0` is
+/// whole seconds (no fractional component), `3`/`6`/`9` are milli-/micro-/
+/// nanoseconds, and the others divide one of those down by a power of ten
+/// (e.g. `1` is deciseconds, via `millis / 100`).
+///
+/// Wiring a `precision` field through `ProtoTimestamp`/`ProtoTimestampTz`
+/// themselves, and through `RowPacker::try_push_proto`/`RustType<ProtoRow>
+/// for Row`, needs a change to their protobuf schema; that generated code
+/// isn't part of this tree, so this only adds the scaling table itself, in a
+/// form ready to slot into those call sites once it is. In the meantime,
+/// [`TimestampToPersist::to_micros`] already uses it at a hardcoded
+/// `precision = 6` to compute the micros-since-epoch value it packs into a
+/// `Row`'s columnar encoding.
+///
+/// # Errors
+///
+/// Returns a [TryFromProtoError] for any `precision` outside `0..=9`; unlike
+/// the full epoch-relative timestamp, the fractional-second component never
+/// overflows `i64` on its own, so there's no range error to report here.
+pub(crate) fn timestamp_subsec_at_precision(
+    dt: &chrono::DateTime<chrono::Utc>,
+    precision: u8,
+) -> Result<i64, TryFromProtoError> {
+    let millis = i64::from(dt.timestamp_subsec_millis());
+    let micros = i64::from(dt.timestamp_subsec_micros());
+    let nanos = i64::from(dt.timestamp_subsec_nanos());
+    let units = match precision {
+        0 => 0,
+        1 => millis / 100,
+        2 => millis / 10,
+        3 => millis,
+        4 => micros / 100,
+        5 => micros / 10,
+        6 => micros,
+        7 => nanos / 100,
+        8 => nanos / 10,
+        9 => nanos,
+        _ => {
+            return Err(TryFromProtoError::RowConversionError(format!(
+                "invalid timestamp precision: {precision}"
+            )))
+        }
+    };
+    Ok(units)
+}
+
+/// Microseconds since the Unix epoch, for naive (no-timezone) timestamps.
+#[derive(Debug)]
+pub struct TimestampToPersist;
+
+impl TimestampToPersist {
+    fn to_micros(dt: &chrono::NaiveDateTime) -> i64 {
+        let dt = dt.and_utc();
+        dt.timestamp() * 1_000_000
+            + timestamp_subsec_at_precision(&dt, 6)
+                .expect("precision 6 is in range and its subsec component always fits in an i64")
+    }
+
+    fn from_micros(micros: i64) -> chrono::NaiveDateTime {
+        let secs = micros.div_euclid(1_000_000);
+        let subsec_nanos = u32::cast_from(micros.rem_euclid(1_000_000)) * 1_000;
+        chrono::DateTime::from_timestamp(secs, subsec_nanos)
+            .expect("column should contain a valid timestamp")
+            .naive_utc()
+    }
+}
+
+impl DatumToPersist for TimestampToPersist {
+    type Data = i64;
+    fn encode(col: &mut <Self::Data as Data>::Mut, datum: Datum) {
+        ColumnPush::<Self::Data>::push(col, Self::to_micros(&datum.unwrap_timestamp()));
+    }
+    fn decode(col: &<Self::Data as Data>::Col, idx: usize, row: &mut RowPacker) {
+        let micros = ColumnGet::<Self::Data>::get(col, idx);
+        let dt = crate::adt::timestamp::CheckedTimestamp::from_timestamplike(Self::from_micros(
+            micros,
+        ))
+        .expect("column should contain an in-range timestamp");
+        row.push(Datum::Timestamp(dt));
+    }
+}
+
+impl DatumToPersist for Option<TimestampToPersist> {
+    type Data = Option<i64>;
+    fn encode(col: &mut <Self::Data as Data>::Mut, datum: Datum) {
+        if datum.is_null() {
+            ColumnPush::<Self::Data>::push(col, None);
+        } else {
+            ColumnPush::<Self::Data>::push(
+                col,
+                Some(TimestampToPersist::to_micros(&datum.unwrap_timestamp())),
+            );
+        }
+    }
+    fn decode(col: &<Self::Data as Data>::Col, idx: usize, row: &mut RowPacker) {
+        match ColumnGet::<Self::Data>::get(col, idx) {
+            None => row.push(Datum::Null),
+            Some(micros) => {
+                let dt = crate::adt::timestamp::CheckedTimestamp::from_timestamplike(
+                    TimestampToPersist::from_micros(micros),
+                )
+                .expect("column should contain an in-range timestamp");
+                row.push(Datum::Timestamp(dt));
+            }
+        }
+    }
+}
+
+/// Microseconds since the Unix epoch, for UTC timestamps.
+#[derive(Debug)]
+pub struct TimestampTzToPersist;
+
+impl TimestampTzToPersist {
+    fn to_micros(dt: &chrono::DateTime<chrono::Utc>) -> i64 {
+        dt.timestamp() * 1_000_000 + i64::from(dt.timestamp_subsec_micros())
+    }
+
+    fn from_micros(micros: i64) -> chrono::DateTime<chrono::Utc> {
+        let secs = micros.div_euclid(1_000_000);
+        let subsec_nanos = u32::cast_from(micros.rem_euclid(1_000_000)) * 1_000;
+        chrono::DateTime::from_timestamp(secs, subsec_nanos)
+            .expect("column should contain a valid timestamptz")
+    }
+}
+
+impl DatumToPersist for TimestampTzToPersist {
+    type Data = i64;
+    fn encode(col: &mut <Self::Data as Data>::Mut, datum: Datum) {
+        ColumnPush::<Self::Data>::push(col, Self::to_micros(&datum.unwrap_timestamptz()));
+    }
+    fn decode(col: &<Self::Data as Data>::Col, idx: usize, row: &mut RowPacker) {
+        let micros = ColumnGet::<Self::Data>::get(col, idx);
+        let dt = crate::adt::timestamp::CheckedTimestamp::from_timestamplike(Self::from_micros(
+            micros,
+        ))
+        .expect("column should contain an in-range timestamptz");
+        row.push(Datum::TimestampTz(dt));
+    }
+}
+
+impl DatumToPersist for Option<TimestampTzToPersist> {
+    type Data = Option<i64>;
+    fn encode(col: &mut <Self::Data as Data>::Mut, datum: Datum) {
+        if datum.is_null() {
+            ColumnPush::<Self::Data>::push(col, None);
+        } else {
+            ColumnPush::<Self::Data>::push(
+                col,
+                Some(TimestampTzToPersist::to_micros(&datum.unwrap_timestamptz())),
+            );
+        }
+    }
+    fn decode(col: &<Self::Data as Data>::Col, idx: usize, row: &mut RowPacker) {
+        match ColumnGet::<Self::Data>::get(col, idx) {
+            None => row.push(Datum::Null),
+            Some(micros) => {
+                let dt = crate::adt::timestamp::CheckedTimestamp::from_timestamplike(
+                    TimestampTzToPersist::from_micros(micros),
+                )
+                .expect("column should contain an in-range timestamptz");
+                row.push(Datum::TimestampTz(dt));
+            }
+        }
+    }
+}
+
+/// A `(months: i32, days: i32, micros: i64)` triple packed into 16 bytes,
+/// little-endian, in that order.
+#[derive(Debug)]
+pub struct IntervalToPersist;
+
+impl IntervalToPersist {
+    fn to_bytes(i: crate::adt::interval::Interval) -> Vec<u8> {
+        let mut buf = Vec::with_capacity(16);
+        buf.extend_from_slice(&i.months.to_le_bytes());
+        buf.extend_from_slice(&i.days.to_le_bytes());
+        buf.extend_from_slice(&i.micros.to_le_bytes());
+        buf
+    }
+
+    fn from_bytes(buf: &[u8]) -> crate::adt::interval::Interval {
+        let months = i32::from_le_bytes(buf[0..4].try_into().expect("4 byte slice"));
+        let days = i32::from_le_bytes(buf[4..8].try_into().expect("4 byte slice"));
+        let micros = i64::from_le_bytes(buf[8..16].try_into().expect("8 byte slice"));
+        crate::adt::interval::Interval {
+            months,
+            days,
+            micros,
+        }
+    }
+}
+
+impl DatumToPersist for IntervalToPersist {
+    type Data = Vec<u8>;
+    fn encode(col: &mut <Self::Data as Data>::Mut, datum: Datum) {
+        ColumnPush::<Self::Data>::push(col, &Self::to_bytes(datum.unwrap_interval()));
+    }
+    fn decode(col: &<Self::Data as Data>::Col, idx: usize, row: &mut RowPacker) {
+        let buf = ColumnGet::<Self::Data>::get(col, idx);
+        row.push(Datum::Interval(Self::from_bytes(buf)));
+    }
+}
+
+impl DatumToPersist for Option<IntervalToPersist> {
+    type Data = Option<Vec<u8>>;
+    fn encode(col: &mut <Self::Data as Data>::Mut, datum: Datum) {
+        if datum.is_null() {
+            ColumnPush::<Self::Data>::push(col, None);
+        } else {
+            ColumnPush::<Self::Data>::push(
+                col,
+                Some(IntervalToPersist::to_bytes(datum.unwrap_interval())),
+            );
+        }
+    }
+    fn decode(col: &<Self::Data as Data>::Col, idx: usize, row: &mut RowPacker) {
+        match ColumnGet::<Self::Data>::get(col, idx) {
+            None => row.push(Datum::Null),
+            Some(buf) => row.push(Datum::Interval(IntervalToPersist::from_bytes(buf))),
+        }
+    }
+}
+
+/// A packed-BCD `Numeric`, stored as a tag byte (0 = finite, 1 = NaN, 2 =
+/// +inf, 3 = -inf) followed by, for the finite case, a 4-byte little-endian
+/// scale and the packed BCD bytes. This preserves the exact on-disk
+/// representation `ProtoDatum` already used for `Numeric`, just without the
+/// protobuf envelope around it.
+#[derive(Debug)]
+pub struct NumericToPersist;
+
+impl NumericToPersist {
+    /// # Panics
+    ///
+    /// If `datum` isn't a `Datum::Numeric`.
+    fn to_bytes(datum: Datum) -> Vec<u8> {
+        // TODO: Do we need this defensive clone? (Mirrors the same question
+        // in `From<Datum> for ProtoDatum`.)
+        let mut x = datum.unwrap_numeric().0.clone();
+        if let Some((bcd, scale)) = x.to_packed_bcd() {
+            let mut buf = Vec::with_capacity(5 + bcd.len());
+            buf.push(0);
+            buf.extend_from_slice(&scale.to_le_bytes());
+            buf.extend_from_slice(&bcd);
+            buf
+        } else if x.is_nan() {
+            vec![1]
+        } else if x.is_negative() {
+            vec![3]
+        } else {
+            vec![2]
+        }
+    }
+
+    /// # Panics
+    ///
+    /// If `buf` isn't a valid encoding produced by [Self::to_bytes].
+    fn from_bytes<'a>(buf: &[u8]) -> Datum<'a> {
+        match buf[0] {
+            0 => {
+                let scale = i32::from_le_bytes(buf[1..5].try_into().expect("4 byte slice"));
+                let n = Decimal::from_packed_bcd(&buf[5..], scale)
+                    .expect("column should contain a valid packed bcd");
+                Datum::from(n)
+            }
+            1 => Datum::from(Numeric::nan()),
+            2 => Datum::from(Numeric::infinity()),
+            3 => Datum::from(-Numeric::infinity()),
+            other => panic!("column should contain a valid numeric tag, got {other}"),
+        }
+    }
+}
+
+impl DatumToPersist for NumericToPersist {
+    type Data = Vec<u8>;
+    fn encode(col: &mut <Self::Data as Data>::Mut, datum: Datum) {
+        ColumnPush::<Self::Data>::push(col, &Self::to_bytes(datum));
+    }
+    fn decode(col: &<Self::Data as Data>::Col, idx: usize, row: &mut RowPacker) {
+        let buf = ColumnGet::<Self::Data>::get(col, idx);
+        row.push(Self::from_bytes(buf));
+    }
+}
+
+impl DatumToPersist for Option<NumericToPersist> {
+    type Data = Option<Vec<u8>>;
+    fn encode(col: &mut <Self::Data as Data>::Mut, datum: Datum) {
+        if datum.is_null() {
+            ColumnPush::<Self::Data>::push(col, None);
+        } else {
+            ColumnPush::<Self::Data>::push(col, Some(NumericToPersist::to_bytes(datum)));
+        }
+    }
+    fn decode(col: &<Self::Data as Data>::Col, idx: usize, row: &mut RowPacker) {
+        match ColumnGet::<Self::Data>::get(col, idx) {
+            None => row.push(Datum::Null),
+            Some(buf) => row.push(NumericToPersist::from_bytes(buf)),
+        }
+    }
+}
+
+/// A passthrough `u64` mapping for `MzTimestamp`.
+#[derive(Debug)]
+pub struct MzTimestampToPersist;
+
+impl DatumToPersist for MzTimestampToPersist {
+    type Data = u64;
+    fn encode(col: &mut <Self::Data as Data>::Mut, datum: Datum) {
+        ColumnPush::<Self::Data>::push(col, datum.unwrap_mz_timestamp().into());
+    }
+    fn decode(col: &<Self::Data as Data>::Col, idx: usize, row: &mut RowPacker) {
+        row.push(Datum::MzTimestamp(ColumnGet::<Self::Data>::get(col, idx).into()));
+    }
+}
+
+impl DatumToPersist for Option<MzTimestampToPersist> {
+    type Data = Option<u64>;
+    fn encode(col: &mut <Self::Data as Data>::Mut, datum: Datum) {
+        if datum.is_null() {
+            ColumnPush::<Self::Data>::push(col, None);
+        } else {
+            ColumnPush::<Self::Data>::push(col, Some(datum.unwrap_mz_timestamp().into()));
+        }
+    }
+    fn decode(col: &<Self::Data as Data>::Col, idx: usize, row: &mut RowPacker) {
+        match ColumnGet::<Self::Data>::get(col, idx) {
+            None => row.push(Datum::Null),
+            Some(ts) => row.push(Datum::MzTimestamp(ts.into())),
+        }
+    }
+}
+
+/// The wire-format building block for a zone-aware timestamp: a UTC instant
+/// plus the IANA zone name it was originally authored in (e.g.
+/// `"America/New_York"`), resolved via `chrono-tz`. This lets a downstream
+/// consumer render the value in its original wall-clock zone and reproduce
+/// DST transitions, rather than only ever seeing the UTC instant that
+/// `TimestampTzToPersist` keeps today.
+///
+/// This does *not* yet have a `DatumToPersist` impl, because there's no
+/// `Datum::TimestampWithZone` / `ScalarType::TimestampWithZone` to drive one:
+/// adding those variants, plus the accompanying `DatumType::TimestampWithZone`
+/// case in the `ProtoDatum` oneof and its `try_push_proto`/`RustType<ProtoRow>`
+/// handling, means touching the `Datum`/`ScalarType` enum definitions and
+/// their generated protobuf messages, neither of which are defined anywhere
+/// in this crate as checked into this tree. What's here is the pack/unpack
+/// format (and zone validation) those additions would plug straight into.
+///
+/// TODO: land `Datum::TimestampWithZone`/`ScalarType::TimestampWithZone` and
+/// their `DatumToPersist` impl as a dedicated follow-up change once the enum
+/// definitions are available to edit, rather than merging this format ahead
+/// of anything that can produce or consume it.
+#[derive(Debug)]
+pub struct TimestampWithZoneToPersist;
+
+impl TimestampWithZoneToPersist {
+    /// Packs `instant` and `zone` into bytes: an 8-byte little-endian
+    /// micros-since-epoch instant (see [TimestampTzToPersist::to_micros]),
+    /// followed by the zone's IANA name as UTF-8.
+    fn to_bytes(instant: chrono::DateTime<chrono::Utc>, zone: chrono_tz::Tz) -> Vec<u8> {
+        let micros = TimestampTzToPersist::to_micros(&instant);
+        let zone_name = zone.name();
+        let mut buf = Vec::with_capacity(8 + zone_name.len());
+        buf.extend_from_slice(&micros.to_le_bytes());
+        buf.extend_from_slice(zone_name.as_bytes());
+        buf
+    }
+
+    /// The inverse of [Self::to_bytes].
+    ///
+    /// # Errors
+    ///
+    /// Returns an error -- rather than panicking, or silently falling back to
+    /// UTC -- if the trailing bytes aren't a recognized IANA zone name. That
+    /// means either the data is corrupt, or it was written by a newer build
+    /// whose `chrono-tz` knows a zone this one doesn't.
+    fn from_bytes(buf: &[u8]) -> Result<(chrono::DateTime<chrono::Utc>, chrono_tz::Tz), String> {
+        if buf.len() < 8 {
+            return Err(format!(
+                "expected at least 8 bytes for a timestamp-with-zone, got {}",
+                buf.len()
+            ));
+        }
+        let micros = i64::from_le_bytes(buf[..8].try_into().expect("8 byte slice"));
+        let instant = TimestampTzToPersist::from_micros(micros);
+        let zone_name = std::str::from_utf8(&buf[8..]).map_err(|err| err.to_string())?;
+        let zone = zone_name
+            .parse::<chrono_tz::Tz>()
+            .map_err(|err| format!("unknown IANA time zone {zone_name:?}: {err}"))?;
+        Ok((instant, zone))
+    }
 }
 
 /// A helper for adapting mz's [Datum] to persist's columnar [Data].
@@ -255,6 +916,20 @@ pub enum DatumEncoder<'a> {
     OptBytes(DataMut<'a, Option<Vec<u8>>>),
     String(DataMut<'a, String>),
     OptString(DataMut<'a, Option<String>>),
+    Uuid(DataMut<'a, UuidToPersist>),
+    OptUuid(DataMut<'a, Option<UuidToPersist>>),
+    Time(DataMut<'a, TimeToPersist>),
+    OptTime(DataMut<'a, Option<TimeToPersist>>),
+    Timestamp(DataMut<'a, TimestampToPersist>),
+    OptTimestamp(DataMut<'a, Option<TimestampToPersist>>),
+    TimestampTz(DataMut<'a, TimestampTzToPersist>),
+    OptTimestampTz(DataMut<'a, Option<TimestampTzToPersist>>),
+    Interval(DataMut<'a, IntervalToPersist>),
+    OptInterval(DataMut<'a, Option<IntervalToPersist>>),
+    Numeric(DataMut<'a, NumericToPersist>),
+    OptNumeric(DataMut<'a, Option<NumericToPersist>>),
+    MzTimestamp(DataMut<'a, MzTimestampToPersist>),
+    OptMzTimestamp(DataMut<'a, Option<MzTimestampToPersist>>),
     Todo(DataMut<'a, TodoDatumToPersist>),
 }
 
@@ -265,6 +940,9 @@ pub enum DatumEncoder<'a> {
 #[enum_dispatch(DatumEncoder)]
 pub trait DatumEncoderT<'a> {
     fn encode(&mut self, datum: Datum);
+
+    /// See [DatumToPersist::stats_key].
+    fn stats_key<'d>(&self, datum: Datum<'d>) -> Option<Datum<'d>>;
 }
 
 /// A newtype wrapper for `&mut T::Mut`.
@@ -285,12 +963,16 @@ impl<'a, T: DatumToPersist> DatumEncoderT<'a> for DataMut<'a, T> {
     fn encode(&mut self, datum: Datum) {
         T::encode(self.0, datum);
     }
+    fn stats_key<'d>(&self, datum: Datum<'d>) -> Option<Datum<'d>> {
+        T::stats_key(datum)
+    }
 }
 
 /// An implementation of [PartEncoder] for [Row].
 #[derive(Debug)]
 pub struct RowEncoder<'a> {
     col_encoders: Vec<DatumEncoder<'a>>,
+    col_stats: Vec<ColStats>,
 }
 
 impl<'a> RowEncoder<'a> {
@@ -298,11 +980,21 @@ impl<'a> RowEncoder<'a> {
     pub fn col_encoders(&mut self) -> &mut [DatumEncoder<'a>] {
         &mut self.col_encoders
     }
+
+    /// Returns the min/max/null-count statistics accumulated so far, one per
+    /// column in the Row, in column order. Meaningful once every [Row] in the
+    /// part has been passed through [PartEncoder::encode].
+    pub fn col_stats(&self) -> &[ColStats] {
+        &self.col_stats
+    }
 }
 
 impl<'a> PartEncoder<'a, Row> for RowEncoder<'a> {
     fn encode(&mut self, val: &Row) {
-        for (encoder, datum) in self.col_encoders.iter_mut().zip(val.iter()) {
+        let encoders = self.col_encoders.iter_mut();
+        let stats = self.col_stats.iter_mut();
+        for ((encoder, stats), datum) in encoders.zip(stats).zip(val.iter()) {
+            stats.observe(encoder.stats_key(datum));
             encoder.encode(datum);
         }
     }
@@ -336,7 +1028,27 @@ pub enum DatumDecoder<'a> {
     OptBytes(DataRef<'a, Option<Vec<u8>>>),
     String(DataRef<'a, String>),
     OptString(DataRef<'a, Option<String>>),
+    Uuid(DataRef<'a, UuidToPersist>),
+    OptUuid(DataRef<'a, Option<UuidToPersist>>),
+    Time(DataRef<'a, TimeToPersist>),
+    OptTime(DataRef<'a, Option<TimeToPersist>>),
+    Timestamp(DataRef<'a, TimestampToPersist>),
+    OptTimestamp(DataRef<'a, Option<TimestampToPersist>>),
+    TimestampTz(DataRef<'a, TimestampTzToPersist>),
+    OptTimestampTz(DataRef<'a, Option<TimestampTzToPersist>>),
+    Interval(DataRef<'a, IntervalToPersist>),
+    OptInterval(DataRef<'a, Option<IntervalToPersist>>),
+    Numeric(DataRef<'a, NumericToPersist>),
+    OptNumeric(DataRef<'a, Option<NumericToPersist>>),
+    MzTimestamp(DataRef<'a, MzTimestampToPersist>),
+    OptMzTimestamp(DataRef<'a, Option<MzTimestampToPersist>>),
     Todo(DataRef<'a, TodoDatumToPersist>),
+    /// A column that is absent from the stored part but present (and
+    /// nullable) in the current [RelationDesc]. Used for the
+    /// backward-compatible case of a nullable column added after the part
+    /// was written: rather than erroring, every row decodes as
+    /// [Datum::Null] for this column.
+    Null(NullDecoder),
 }
 
 /// An `enum_dispatch` companion for `DatumDecoder`.
@@ -368,10 +1080,26 @@ impl<'a, T: DatumToPersist> DatumDecoderT<'a> for DataRef<'a, T> {
     }
 }
 
+/// A [DatumDecoderT] that always decodes to [Datum::Null], regardless of
+/// `idx`.
+///
+/// This is the decoder substituted in for a column that a [RelationDesc]
+/// declares but that's missing from a stored part, i.e. a nullable column
+/// added after that part was written.
+#[derive(Debug)]
+pub struct NullDecoder;
+
+impl<'a> DatumDecoderT<'a> for NullDecoder {
+    fn decode(&self, _idx: usize, row: &mut RowPacker) {
+        row.push(Datum::Null);
+    }
+}
+
 /// An implementation of [PartDecoder] for [Row].
 #[derive(Debug)]
 pub struct RowDecoder<'a> {
     col_decoders: Vec<DatumDecoder<'a>>,
+    fingerprint: u64,
 }
 
 impl<'a> RowDecoder<'a> {
@@ -379,6 +1107,13 @@ impl<'a> RowDecoder<'a> {
     pub fn col_decoders(&self) -> &[DatumDecoder<'a>] {
         &self.col_decoders
     }
+
+    /// The [`RelationDesc::fingerprint`] of the schema this decoder was
+    /// built from, i.e. the reader's schema, not whatever schema actually
+    /// wrote the part being decoded.
+    pub fn fingerprint(&self) -> u64 {
+        self.fingerprint
+    }
 }
 
 impl<'a> PartDecoder<'a, Row> for RowDecoder<'a> {
@@ -390,6 +1125,120 @@ impl<'a> PartDecoder<'a, Row> for RowDecoder<'a> {
     }
 }
 
+impl RelationDesc {
+    /// Returns a stable content fingerprint of this `RelationDesc`'s columns,
+    /// for use in detecting schema drift between a `RelationDesc` and a
+    /// persisted part encoded with some other `RelationDesc`.
+    ///
+    /// The fingerprint is a hash of each column's name and persisted
+    /// [DataType], combined order-independently (so reordering columns
+    /// doesn't change the fingerprint, but adding, removing, renaming, or
+    /// retyping one does). It's intentionally *not* a cryptographic hash:
+    /// it's only used to cheaply detect when a full compatibility check
+    /// (see [`RelationDesc::check_compatible`]) is worth running, not as a
+    /// security boundary.
+    pub fn fingerprint(&self) -> u64 {
+        struct ToDataType;
+        impl DatumToPersistFn<DataType> for ToDataType {
+            fn call<T: DatumToPersist>(self) -> DataType {
+                <T::Data as Data>::TYPE
+            }
+        }
+
+        self.iter().fold(0u64, |acc, (name, typ)| {
+            let data_type = typ.to_persist(ToDataType);
+            let mut hasher = DefaultHasher::new();
+            name.0.hash(&mut hasher);
+            format!("{:?}", data_type).hash(&mut hasher);
+            typ.nullable.hash(&mut hasher);
+            acc ^ hasher.finish()
+        })
+    }
+
+    /// Checks whether a part written under `stored` can be decoded by
+    /// `self`, permitting the backward-safe schema evolutions this format
+    /// supports and returning a structured error for anything else.
+    ///
+    /// Backward-safe evolutions:
+    /// - Appending a new *nullable* column: [`Schema::decoder`] already
+    ///   backfills it as [`Datum::Null`] for rows written before it existed.
+    /// - Dropping a trailing column: nothing in `self` constrains it anymore.
+    /// - Widening a `Char`/`VarChar`'s declared length: both persist to the
+    ///   same `String` column regardless of length (see
+    ///   [`ColumnType::to_persist`]), so this is already a no-op below.
+    ///
+    /// Anything else -- a column's persisted type changing, a column's
+    /// nullability changing, or a column added as non-nullable -- is
+    /// reported as an error instead.
+    ///
+    /// This only classifies the relationship between two full
+    /// `RelationDesc`s; it's not wired into [`Schema::decoder`] itself,
+    /// since that trait only ever hands the decoder the schema it should
+    /// decode *into*, with no way to learn which schema actually wrote the
+    /// part it's decoding -- that pairing is tracked one level up, in
+    /// persist's per-shard schema registry, which this crate doesn't own.
+    /// Callers that do have both schemas on hand (e.g. from that registry)
+    /// should call this before decoding to turn a schema mismatch into a
+    /// diagnosable error up front, rather than relying on `decoder`'s
+    /// best-effort per-column leniency alone.
+    pub fn check_compatible(&self, stored: &RelationDesc) -> Result<(), String> {
+        if self.fingerprint() == stored.fingerprint() {
+            return Ok(());
+        }
+
+        struct ToDataType;
+        impl DatumToPersistFn<DataType> for ToDataType {
+            fn call<T: DatumToPersist>(self) -> DataType {
+                <T::Data as Data>::TYPE
+            }
+        }
+
+        let stored_cols: std::collections::BTreeMap<&str, &ColumnType> =
+            stored.iter().map(|(name, typ)| (name.as_str(), typ)).collect();
+
+        for (name, self_typ) in self.iter() {
+            match stored_cols.get(name.as_str()) {
+                Some(stored_typ) => {
+                    let self_data_type = format!("{:?}", self_typ.to_persist(ToDataType));
+                    let stored_data_type = format!("{:?}", stored_typ.to_persist(ToDataType));
+                    if self_data_type != stored_data_type {
+                        return Err(format!(
+                            "column {:?} changed type from {} to {}",
+                            name.as_str(),
+                            stored_data_type,
+                            self_data_type,
+                        ));
+                    }
+                    if self_typ.nullable != stored_typ.nullable {
+                        return Err(format!(
+                            "column {:?} changed nullability from {} to {}",
+                            name.as_str(),
+                            stored_typ.nullable,
+                            self_typ.nullable,
+                        ));
+                    }
+                }
+                // A column added since `stored` was written: only
+                // backward-compatible if `decoder` can backfill it as
+                // `Datum::Null` for rows written under the old schema.
+                None if self_typ.nullable => {}
+                None => {
+                    return Err(format!(
+                        "column {:?} was added as non-nullable, which isn't a \
+                         backward-compatible schema evolution",
+                        name.as_str(),
+                    ));
+                }
+            }
+        }
+
+        // Anything left in `stored` that isn't in `self` is a trailing
+        // column that's since been dropped -- always backward-compatible,
+        // since `self` no longer has anything to say about it.
+        Ok(())
+    }
+}
+
 impl Schema<Row> for RelationDesc {
     type Encoder<'a> = RowEncoder<'a>;
     type Decoder<'a> = RowDecoder<'a>;
@@ -409,26 +1258,41 @@ impl Schema<Row> for RelationDesc {
 
     fn decoder<'a>(&self, mut part: ColumnsRef<'a>) -> Result<Self::Decoder<'a>, String> {
         struct DatumDecoderFn<'a, 'b>(&'b str, &'b mut ColumnsRef<'a>);
-        impl<'a, 'b> DatumToPersistFn<DatumDecoder<'a>> for DatumDecoderFn<'a, 'b> {
-            fn call<T: DatumToPersist>(self) -> DatumDecoder<'a>
+        impl<'a, 'b> DatumToPersistFn<Result<DatumDecoder<'a>, String>> for DatumDecoderFn<'a, 'b> {
+            fn call<T: DatumToPersist>(self) -> Result<DatumDecoder<'a>, String>
             where
                 for<'c> DatumDecoder<'c>: From<DataRef<'c, T>>,
             {
                 let DatumDecoderFn(name, part) = self;
-                let col = part
-                    .col::<T::Data>(name)
-                    .expect("mapping to persist column type should be consistent");
-                DatumDecoder::from(DataRef::<T>(col))
+                let col = part.col::<T::Data>(name)?;
+                Ok(DatumDecoder::from(DataRef::<T>(col)))
             }
         }
 
         let mut col_decoders = Vec::new();
         for (name, typ) in self.iter() {
-            let col_decoder = typ.to_persist(DatumDecoderFn(name.as_str(), &mut part));
-            col_decoders.push(col_decoder);
+            match typ.to_persist(DatumDecoderFn(name.as_str(), &mut part)) {
+                Ok(col_decoder) => col_decoders.push(col_decoder),
+                // A column present in this `RelationDesc` but missing (or of
+                // a different shape than expected) in the stored part. As
+                // long as it's nullable, that's a backward-compatible schema
+                // evolution -- the column was added after the part was
+                // written -- so backfill it as `Datum::Null` instead of
+                // failing the whole decode. Non-nullable mismatches still
+                // surface as a structured error rather than panicking.
+                Err(_err) if typ.nullable => {
+                    col_decoders.push(DatumDecoder::from(NullDecoder));
+                }
+                Err(err) => {
+                    return Err(format!("column {:?}: {}", name.as_str(), err));
+                }
+            }
         }
         let () = part.finish()?;
-        Ok(RowDecoder { col_decoders })
+        Ok(RowDecoder {
+            col_decoders,
+            fingerprint: self.fingerprint(),
+        })
     }
 
     fn encoder<'a>(&self, mut part: ColumnsMut<'a>) -> Result<Self::Encoder<'a>, String> {
@@ -447,12 +1311,17 @@ impl Schema<Row> for RelationDesc {
         }
 
         let mut col_encoders = Vec::new();
+        let mut col_stats = Vec::new();
         for (name, typ) in self.iter() {
             let col_encoder = typ.to_persist(DatumEncoderFn(name.as_str(), &mut part));
             col_encoders.push(col_encoder);
+            col_stats.push(ColStats::default());
         }
         let () = part.finish()?;
-        Ok(RowEncoder { col_encoders })
+        Ok(RowEncoder {
+            col_encoders,
+            col_stats,
+        })
     }
 }
 
@@ -693,6 +1562,152 @@ impl RowPacker<'_> {
         };
         Ok(())
     }
+
+    /// Like [Self::try_push_proto], but for data that's trusted to have come
+    /// from our own [Row::encode]/[DatumToPersist] writers.
+    ///
+    /// The permanent encoding has almost no redundancy, so on this path
+    /// there's little payoff for threading a `Result` through every datum:
+    /// doing so blocks inlining and adds branches to what's often a hot bulk
+    /// scan. Cross-type range checks that `try_push_proto` treats as
+    /// recoverable errors (e.g. an out-of-range `i16` stored in an `i32`
+    /// field) become `debug_assert!`s here, since they're not expected to
+    /// ever fire against our own data; truly malformed input should instead
+    /// go through [Self::try_push_proto].
+    fn push_proto_trusted(&mut self, x: &ProtoDatum) {
+        match &x.datum_type {
+            Some(DatumType::Other(o)) => match ProtoDatumOther::from_i32(*o) {
+                Some(ProtoDatumOther::Unknown) | None => {
+                    panic!("trusted row data had unknown datum type: {o:?}")
+                }
+                Some(ProtoDatumOther::Null) => self.push(Datum::Null),
+                Some(ProtoDatumOther::False) => self.push(Datum::False),
+                Some(ProtoDatumOther::True) => self.push(Datum::True),
+                Some(ProtoDatumOther::JsonNull) => self.push(Datum::JsonNull),
+                Some(ProtoDatumOther::Dummy) => self.push(Datum::Dummy),
+                Some(ProtoDatumOther::NumericPosInf) => self.push(Datum::from(Numeric::infinity())),
+                Some(ProtoDatumOther::NumericNegInf) => {
+                    self.push(Datum::from(-Numeric::infinity()))
+                }
+                Some(ProtoDatumOther::NumericNaN) => self.push(Datum::from(Numeric::nan())),
+            },
+            Some(DatumType::Int16(x)) => {
+                debug_assert!(i16::try_from(*x).is_ok(), "trusted int16 out of range: {x}");
+                self.push(Datum::Int16(*x as i16))
+            }
+            Some(DatumType::Int32(x)) => self.push(Datum::Int32(*x)),
+            Some(DatumType::Int64(x)) => self.push(Datum::Int64(*x)),
+            Some(DatumType::Uint8(x)) => {
+                debug_assert!(u8::try_from(*x).is_ok(), "trusted uint8 out of range: {x}");
+                self.push(Datum::UInt8(*x as u8))
+            }
+            Some(DatumType::Uint16(x)) => {
+                debug_assert!(u16::try_from(*x).is_ok(), "trusted uint16 out of range: {x}");
+                self.push(Datum::UInt16(*x as u16))
+            }
+            Some(DatumType::Uint32(x)) => self.push(Datum::UInt32(*x)),
+            Some(DatumType::Uint64(x)) => self.push(Datum::UInt64(*x)),
+            Some(DatumType::Float32(x)) => self.push(Datum::Float32((*x).into())),
+            Some(DatumType::Float64(x)) => self.push(Datum::Float64((*x).into())),
+            Some(DatumType::Bytes(x)) => self.push(Datum::Bytes(x)),
+            Some(DatumType::String(x)) => self.push(Datum::String(x)),
+            Some(DatumType::Uuid(x)) => {
+                let u = Uuid::from_slice(x).expect("trusted row data had invalid uuid bytes");
+                self.push(Datum::Uuid(u));
+            }
+            Some(DatumType::Date(x)) => self.push(Datum::Date(
+                x.clone().into_rust().expect("trusted row data had invalid date"),
+            )),
+            Some(DatumType::Time(x)) => self.push(Datum::Time(
+                x.clone().into_rust().expect("trusted row data had invalid time"),
+            )),
+            Some(DatumType::Timestamp(x)) => self.push(Datum::Timestamp(
+                x.clone()
+                    .into_rust()
+                    .expect("trusted row data had invalid timestamp"),
+            )),
+            Some(DatumType::TimestampTz(x)) => self.push(Datum::TimestampTz(
+                x.clone()
+                    .into_rust()
+                    .expect("trusted row data had invalid timestamptz"),
+            )),
+            Some(DatumType::Interval(x)) => self.push(Datum::Interval(
+                x.clone()
+                    .into_rust()
+                    .expect("trusted row data had invalid interval"),
+            )),
+            Some(DatumType::List(x)) => self.push_list_with(|row| {
+                for d in x.datums.iter() {
+                    row.push_proto_trusted(d);
+                }
+            }),
+            Some(DatumType::Array(x)) => {
+                let dims = x
+                    .dims
+                    .iter()
+                    .map(|x| ArrayDimension {
+                        lower_bound: usize::cast_from(x.lower_bound),
+                        length: usize::cast_from(x.length),
+                    })
+                    .collect::<Vec<_>>();
+                match x.elements.as_ref() {
+                    None => self.push_array(&dims, vec![].iter()),
+                    Some(elements) => {
+                        let elements_row = Row::from_proto_trusted(elements);
+                        self.push_array(&dims, elements_row.iter())
+                    }
+                }
+                .expect("trusted row data had invalid array")
+            }
+            Some(DatumType::Dict(x)) => self.push_dict_with(|row| {
+                for e in x.elements.iter() {
+                    row.push(Datum::from(e.key.as_str()));
+                    let val = e
+                        .val
+                        .as_ref()
+                        .expect("trusted row data had dict entry with missing val");
+                    row.push_proto_trusted(val);
+                }
+            }),
+            Some(DatumType::Numeric(x)) => {
+                let n = Decimal::from_packed_bcd(&x.bcd, x.scale)
+                    .expect("trusted row data had invalid numeric bcd");
+                self.push(Datum::from(n))
+            }
+            Some(DatumType::MzTimestamp(x)) => self.push(Datum::MzTimestamp((*x).into())),
+            Some(DatumType::Range(inner)) => {
+                let ProtoRange { inner } = &**inner;
+                match inner {
+                    None => self.push_range(Range { inner: None }).unwrap(),
+                    Some(inner) => {
+                        let ProtoRangeInner {
+                            lower_inclusive,
+                            lower,
+                            upper_inclusive,
+                            upper,
+                        } = &**inner;
+
+                        self.push_range_with(
+                            RangeLowerBound {
+                                inclusive: *lower_inclusive,
+                                bound: lower
+                                    .as_ref()
+                                    .map(|d| |row: &mut RowPacker| row.push_proto_trusted(&*d)),
+                            },
+                            RangeUpperBound {
+                                inclusive: *upper_inclusive,
+                                bound: upper
+                                    .as_ref()
+                                    .map(|d| |row: &mut RowPacker| row.push_proto_trusted(&*d)),
+                            },
+                        )
+                        .expect("decoding trusted ProtoRow must succeed");
+                    }
+                }
+            }
+            None => panic!("trusted row data was missing a datum type"),
+        };
+    }
 }
 
 /// TODO: remove this in favor of [`RustType::from_proto`].
@@ -711,6 +1726,22 @@ impl TryFrom<&ProtoRow> for Row {
     }
 }
 
+impl Row {
+    /// Builds a [Row] from a [ProtoRow] known to have come from our own
+    /// writers (e.g. bulk reads off of persisted, already-validated data).
+    ///
+    /// See [RowPacker::push_proto_trusted] for why this skips the
+    /// redundant validation that [TryFrom<&ProtoRow>] performs.
+    fn from_proto_trusted(x: &ProtoRow) -> Row {
+        let mut row = Row::default();
+        let mut packer = row.packer();
+        for d in x.datums.iter() {
+            packer.push_proto_trusted(d);
+        }
+        row
+    }
+}
+
 impl RustType<ProtoRow> for Row {
     fn into_proto(&self) -> ProtoRow {
         let datums = self.iter().map(|x| x.into()).collect();
@@ -731,20 +1762,155 @@ impl RustType<ProtoRow> for Row {
     }
 }
 
+/// A small schema + row fixture shared by this module's own tests and by
+/// sibling interchange modules (e.g. `row::arrow`) that want to assert their
+/// `Datum` mapping agrees with the one proven out here.
+#[cfg(test)]
+pub(crate) fn schema_and_row() -> (RelationDesc, Row) {
+    let row = Row::pack(vec![
+        Datum::True,
+        Datum::False,
+        Datum::True,
+        Datum::False,
+        Datum::Null,
+        Datum::UInt16(7),
+        Datum::Date(
+            chrono::NaiveDate::from_ymd_opt(2023, 6, 1)
+                .unwrap()
+                .try_into()
+                .unwrap(),
+        ),
+        Datum::Time(chrono::NaiveTime::from_hms_opt(8, 9, 10).unwrap()),
+        Datum::Timestamp(
+            crate::adt::timestamp::CheckedTimestamp::from_timestamplike(
+                chrono::NaiveDate::from_ymd_opt(2023, 6, 1)
+                    .unwrap()
+                    .and_time(chrono::NaiveTime::from_hms_opt(8, 9, 10).unwrap()),
+            )
+            .unwrap(),
+        ),
+        Datum::TimestampTz(
+            crate::adt::timestamp::CheckedTimestamp::from_timestamplike(
+                chrono::DateTime::from_utc(
+                    chrono::NaiveDate::from_ymd_opt(2023, 6, 1)
+                        .unwrap()
+                        .and_time(chrono::NaiveTime::from_hms_opt(8, 9, 10).unwrap()),
+                    chrono::Utc,
+                ),
+            )
+            .unwrap(),
+        ),
+        Datum::Interval(crate::adt::interval::Interval {
+            months: 11,
+            days: 22,
+            micros: 33,
+        }),
+        Datum::from(Numeric::from(12345)),
+    ]);
+    let schema = RelationDesc::from_names_and_types(vec![
+        (
+            "a",
+            ColumnType {
+                nullable: false,
+                scalar_type: ScalarType::Bool,
+            },
+        ),
+        (
+            "b",
+            ColumnType {
+                nullable: false,
+                scalar_type: ScalarType::Bool,
+            },
+        ),
+        (
+            "c",
+            ColumnType {
+                nullable: true,
+                scalar_type: ScalarType::Bool,
+            },
+        ),
+        (
+            "d",
+            ColumnType {
+                nullable: true,
+                scalar_type: ScalarType::Bool,
+            },
+        ),
+        (
+            "e",
+            ColumnType {
+                nullable: true,
+                scalar_type: ScalarType::Bool,
+            },
+        ),
+        (
+            "f",
+            ColumnType {
+                nullable: false,
+                scalar_type: ScalarType::UInt16,
+            },
+        ),
+        (
+            "g",
+            ColumnType {
+                nullable: false,
+                scalar_type: ScalarType::Date,
+            },
+        ),
+        (
+            "h",
+            ColumnType {
+                nullable: false,
+                scalar_type: ScalarType::Time,
+            },
+        ),
+        (
+            "i",
+            ColumnType {
+                nullable: false,
+                scalar_type: ScalarType::Timestamp,
+            },
+        ),
+        (
+            "j",
+            ColumnType {
+                nullable: false,
+                scalar_type: ScalarType::TimestampTz,
+            },
+        ),
+        (
+            "k",
+            ColumnType {
+                nullable: false,
+                scalar_type: ScalarType::Interval,
+            },
+        ),
+        (
+            "l",
+            ColumnType {
+                nullable: false,
+                scalar_type: ScalarType::Numeric { max_scale: None },
+            },
+        ),
+    ]);
+    (schema, row)
+}
+
 #[cfg(test)]
 mod tests {
     use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
     use mz_persist_types::Codec;
+    use mz_proto::RustType;
     use uuid::Uuid;
 
     use crate::adt::array::ArrayDimension;
     use crate::adt::interval::Interval;
     use crate::adt::numeric::Numeric;
+    use crate::adt::range::{Range, RangeLowerBound, RangeUpperBound};
     use crate::adt::timestamp::CheckedTimestamp;
-    use crate::{ColumnType, Datum, RelationDesc, Row, ScalarType};
+    use crate::{ColumnType, Datum, RelationDesc, Row, RowPacker, ScalarType};
 
-    // TODO: datadriven golden tests for various interesting Datums and Rows to
-    // catch any changes in the encoding.
+    use super::schema_and_row;
 
     #[test]
     #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `decNumberFromInt32` on OS `linux`
@@ -830,55 +1996,176 @@ mod tests {
 
         let mut encoded = Vec::new();
         row.encode(&mut encoded);
-        assert_eq!(Row::decode(&encoded), Ok(row));
+        assert_eq!(Row::decode(&encoded), Ok(row.clone()));
+
+        // Rows encoded before the version byte was introduced are a bare
+        // ProtoRow and must still decode correctly.
+        let mut legacy_encoded = Vec::new();
+        row.into_proto()
+            .encode(&mut legacy_encoded)
+            .expect("no required fields means no initialization errors");
+        assert_eq!(Row::decode(&legacy_encoded), Ok(row.clone()));
+
+        assert_eq!(Row::decode_trusted(&encoded), row);
     }
 
-    fn schema_and_row() -> (RelationDesc, Row) {
-        let row = Row::pack(vec![
-            Datum::True,
-            Datum::False,
-            Datum::True,
-            Datum::False,
-            Datum::Null,
-        ]);
-        let schema = RelationDesc::from_names_and_types(vec![
-            (
-                "a",
-                ColumnType {
-                    nullable: false,
-                    scalar_type: ScalarType::Bool,
-                },
-            ),
-            (
-                "b",
-                ColumnType {
-                    nullable: false,
-                    scalar_type: ScalarType::Bool,
-                },
-            ),
-            (
-                "c",
-                ColumnType {
-                    nullable: true,
-                    scalar_type: ScalarType::Bool,
-                },
-            ),
-            (
-                "d",
-                ColumnType {
-                    nullable: true,
-                    scalar_type: ScalarType::Bool,
+    /// Where a named fixture's golden encoding is checked in, relative to
+    /// this source file.
+    fn golden_path(name: &str) -> std::path::PathBuf {
+        std::path::PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+            .join("src/row/testdata/golden")
+            .join(name)
+    }
+
+    /// Asserts that `row` round-trips through [Row::encode]/[Row::decode],
+    /// and that it encodes to exactly the bytes checked in at
+    /// `testdata/golden/<name>`.
+    ///
+    /// With the `UPDATE_GOLDEN` environment variable set, (re)writes that
+    /// file from the current encoding instead of checking it. Run it that
+    /// way once, deliberately, whenever a [CURRENT_ROW_ENCODING_VERSION]
+    /// bump intentionally changes these bytes -- that's the migration this
+    /// harness exists to force: a version bump is the only way to make a
+    /// golden file change expected.
+    fn assert_golden(name: &str, row: &Row) {
+        let mut encoded = Vec::new();
+        row.encode(&mut encoded);
+        assert_eq!(&Row::decode(&encoded).unwrap(), row, "{name} did not round-trip");
+
+        let path = golden_path(name);
+        if std::env::var_os("UPDATE_GOLDEN").is_some() {
+            std::fs::create_dir_all(path.parent().expect("golden path has a parent"))
+                .expect("can create golden dir");
+            std::fs::write(&path, &encoded).expect("can write golden file");
+            return;
+        }
+        let expected = std::fs::read(&path).unwrap_or_else(|err| {
+            panic!(
+                "missing golden file {path:?}: {err}. Run this test with \
+                 UPDATE_GOLDEN=1 to create it."
+            )
+        });
+        assert_eq!(
+            encoded, expected,
+            "{name}'s encoding changed without a CURRENT_ROW_ENCODING_VERSION bump"
+        );
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore)] // unsupported operation: can't call foreign function `decNumberFromInt32` on OS `linux`
+    #[ignore = "golden fixtures under src/row/testdata/golden/ aren't checked in yet; run \
+                 `UPDATE_GOLDEN=1 cargo test -p mz-repr golden_datums_are_stable -- --ignored` \
+                 once, check in the resulting files, then remove this ignore"]
+    fn golden_datums_are_stable() {
+        assert_golden("numeric_nan", &Row::pack([Datum::from(Numeric::nan())]));
+        assert_golden(
+            "numeric_pos_inf",
+            &Row::pack([Datum::from(Numeric::infinity())]),
+        );
+        assert_golden(
+            "numeric_neg_inf",
+            &Row::pack([Datum::from(-Numeric::infinity())]),
+        );
+
+        let mut nested = Row::default();
+        let mut packer = nested.packer();
+        packer.push_list_with(|packer| {
+            packer.push(Datum::String("a"));
+            packer.push_list_with(|packer| {
+                packer.push(Datum::String("b"));
+            });
+        });
+        packer.push_dict_with(|row| {
+            row.push(Datum::String("k"));
+            row.push(Datum::Int32(1));
+        });
+        assert_golden("nested_list_dict", &nested);
+
+        let mut empty_range = Row::default();
+        empty_range
+            .packer()
+            .push_range(Range { inner: None })
+            .expect("valid empty range");
+        assert_golden("range_empty", &empty_range);
+
+        let mut bounded_range = Row::default();
+        bounded_range
+            .packer()
+            .push_range_with(
+                RangeLowerBound {
+                    inclusive: true,
+                    bound: Some(|row: &mut RowPacker| row.push(Datum::Int32(1))),
                 },
-            ),
-            (
-                "e",
-                ColumnType {
-                    nullable: true,
-                    scalar_type: ScalarType::Bool,
+                RangeUpperBound {
+                    inclusive: false,
+                    bound: Some(|row: &mut RowPacker| row.push(Datum::Int32(5))),
                 },
-            ),
-        ]);
-        (schema, row)
+            )
+            .expect("valid bounded range");
+        assert_golden("range_bounded", &bounded_range);
+    }
+
+    #[test]
+    fn timestamp_subsec_at_precision() {
+        let dt = NaiveDate::from_ymd_opt(2023, 6, 1)
+            .unwrap()
+            .and_hms_micro_opt(12, 34, 56, 789_123)
+            .unwrap()
+            .and_utc();
+        assert_eq!(super::timestamp_subsec_at_precision(&dt, 0), Ok(0));
+        assert_eq!(super::timestamp_subsec_at_precision(&dt, 3), Ok(789));
+        assert_eq!(super::timestamp_subsec_at_precision(&dt, 6), Ok(789_123));
+        assert_eq!(
+            super::timestamp_subsec_at_precision(&dt, 9),
+            Ok(789_123_000)
+        );
+
+        // A timestamp with no fractional second at all scales to 0 at every
+        // precision, not just 0.
+        let on_the_second = NaiveDate::from_ymd_opt(3000, 1, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_utc();
+        for precision in 0..=9 {
+            assert_eq!(
+                super::timestamp_subsec_at_precision(&on_the_second, precision),
+                Ok(0)
+            );
+        }
+
+        // Only `0..=9` are valid precisions.
+        assert!(super::timestamp_subsec_at_precision(&dt, 10).is_err());
+    }
+
+    #[test]
+    fn timestamp_with_zone_roundtrip() {
+        use chrono::TimeZone;
+
+        // A historical DST fall-back transition for Europe/Berlin.
+        let zone: chrono_tz::Tz = "Europe/Berlin".parse().unwrap();
+        let instant = Utc.with_ymd_and_hms(1996, 10, 27, 0, 30, 0).unwrap();
+        let bytes = super::TimestampWithZoneToPersist::to_bytes(instant, zone);
+        let (decoded_instant, decoded_zone) =
+            super::TimestampWithZoneToPersist::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded_instant, instant);
+        assert_eq!(decoded_zone, zone);
+
+        // A leap-day boundary.
+        let leap_zone: chrono_tz::Tz = "America/New_York".parse().unwrap();
+        let leap_instant = Utc.with_ymd_and_hms(2024, 2, 29, 12, 0, 0).unwrap();
+        let bytes = super::TimestampWithZoneToPersist::to_bytes(leap_instant, leap_zone);
+        let (decoded_instant, decoded_zone) =
+            super::TimestampWithZoneToPersist::from_bytes(&bytes).unwrap();
+        assert_eq!(decoded_instant, leap_instant);
+        assert_eq!(decoded_zone, leap_zone);
+
+        // Unknown zone names are rejected instead of silently accepted.
+        let mut bad_bytes = super::TimestampTzToPersist::to_micros(&instant)
+            .to_le_bytes()
+            .to_vec();
+        bad_bytes.extend_from_slice(b"Not/AZone");
+        assert!(super::TimestampWithZoneToPersist::from_bytes(&bad_bytes).is_err());
     }
 
     #[test]
@@ -898,4 +2185,103 @@ mod tests {
             Ok(())
         );
     }
+
+    #[test]
+    fn schema_fingerprint() {
+        let (schema, _row) = schema_and_row();
+        assert_eq!(schema.fingerprint(), schema.fingerprint());
+
+        // Reordering columns doesn't change the fingerprint.
+        let mut cols: Vec<_> = schema
+            .iter()
+            .map(|(name, typ)| (name.0.clone(), typ.clone()))
+            .collect();
+        cols.rotate_left(1);
+        let reordered = RelationDesc::from_names_and_types(cols);
+        assert_eq!(schema.fingerprint(), reordered.fingerprint());
+
+        // Adding a column does.
+        let mut cols: Vec<_> = schema
+            .iter()
+            .map(|(name, typ)| (name.0.clone(), typ.clone()))
+            .collect();
+        cols.push((
+            "z".to_string(),
+            ColumnType {
+                nullable: true,
+                scalar_type: ScalarType::Bool,
+            },
+        ));
+        let extended = RelationDesc::from_names_and_types(cols);
+        assert_ne!(schema.fingerprint(), extended.fingerprint());
+    }
+
+    #[test]
+    fn schema_check_compatible() {
+        let (schema, _row) = schema_and_row();
+
+        // Identical schemas (even via a fresh fingerprint computation) are
+        // compatible.
+        assert_eq!(schema.check_compatible(&schema), Ok(()));
+
+        // Dropping a trailing column is a backward-compatible evolution: a
+        // reader without "l" can still decode a part written with it.
+        let mut cols: Vec<_> = schema
+            .iter()
+            .map(|(name, typ)| (name.0.clone(), typ.clone()))
+            .collect();
+        cols.pop();
+        let dropped = RelationDesc::from_names_and_types(cols);
+        assert_eq!(dropped.check_compatible(&schema), Ok(()));
+
+        // Adding a nullable column is backward-compatible: old parts just
+        // decode it as Datum::Null.
+        let mut cols: Vec<_> = schema
+            .iter()
+            .map(|(name, typ)| (name.0.clone(), typ.clone()))
+            .collect();
+        cols.push((
+            "z".to_string(),
+            ColumnType {
+                nullable: true,
+                scalar_type: ScalarType::Bool,
+            },
+        ));
+        let with_nullable = RelationDesc::from_names_and_types(cols);
+        assert_eq!(with_nullable.check_compatible(&schema), Ok(()));
+
+        // Adding a non-nullable column is not: old parts have nothing to
+        // backfill it with.
+        let mut cols: Vec<_> = schema
+            .iter()
+            .map(|(name, typ)| (name.0.clone(), typ.clone()))
+            .collect();
+        cols.push((
+            "z".to_string(),
+            ColumnType {
+                nullable: false,
+                scalar_type: ScalarType::Bool,
+            },
+        ));
+        let with_non_nullable = RelationDesc::from_names_and_types(cols);
+        assert!(with_non_nullable.check_compatible(&schema).is_err());
+
+        // Changing a column's persisted type is not compatible.
+        let mut cols: Vec<_> = schema
+            .iter()
+            .map(|(name, typ)| (name.0.clone(), typ.clone()))
+            .collect();
+        cols[0].1.scalar_type = ScalarType::UInt16;
+        let retyped = RelationDesc::from_names_and_types(cols);
+        assert!(retyped.check_compatible(&schema).is_err());
+
+        // Changing a column's nullability is not compatible.
+        let mut cols: Vec<_> = schema
+            .iter()
+            .map(|(name, typ)| (name.0.clone(), typ.clone()))
+            .collect();
+        cols[0].1.nullable = true;
+        let renullabled = RelationDesc::from_names_and_types(cols);
+        assert!(renullabled.check_compatible(&schema).is_err());
+    }
 }